@@ -0,0 +1,39 @@
+use tauri::AppHandle;
+
+use super::python_bridge;
+
+const DEFAULT_STRENGTH: i32 = 50;
+
+/// Maps a 0-100 strength slider to ffmpeg's `afftdn` noise-reduction amount (`nr`) and
+/// noise floor (`nf`, dB).
+fn filter_params(strength: i32) -> (f64, f64) {
+    let strength = strength.clamp(0, 100) as f64;
+    let nr = 0.01 + (strength / 100.0) * 96.99; // afftdn nr range: 0.01-97
+    let nf = -80.0 + (strength / 100.0) * 55.0; // afftdn nf, mapped onto -80 to -25 dB
+    (nr, nf)
+}
+
+/// Denoises `input_path` with ffmpeg's `afftdn` filter via the bundled
+/// audio_helper.py. `strength` (0-100) controls how aggressive the filter is; `None`
+/// defaults to a middle value rather than silently skipping the request.
+#[tauri::command]
+pub fn denoise_audio_ffmpeg(
+    app: AppHandle,
+    input_path: String,
+    output_path: String,
+    strength: Option<i32>,
+) -> Result<(), String> {
+    let strength = strength.unwrap_or(DEFAULT_STRENGTH).clamp(0, 100);
+    let (nr, nf) = filter_params(strength);
+
+    let args = serde_json::json!({
+        "action": "denoise",
+        "input_path": input_path,
+        "output_path": output_path,
+        "strength": strength,
+        "nr": nr,
+        "nf": nf,
+    });
+    python_bridge::run_python_audio_command(&app, &args)?;
+    Ok(())
+}
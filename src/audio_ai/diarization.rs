@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiarizedSegment {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiarizationResult {
+    pub segments: Vec<DiarizedSegment>,
+}
+
+/// Concatenates consecutive segments from the same speaker when the gap between them
+/// is at most `max_gap` seconds, joining their text with a space. Diarization tends to
+/// split one person's continuous speech into many tiny segments; this collapses them
+/// back into something readable without touching the underlying word-level timing.
+#[tauri::command]
+pub fn merge_speaker_segments(result: DiarizationResult, max_gap: f64) -> DiarizationResult {
+    let mut merged: Vec<DiarizedSegment> = Vec::new();
+
+    for segment in result.segments {
+        match merged.last_mut() {
+            Some(prev) if prev.speaker == segment.speaker && segment.start - prev.end <= max_gap => {
+                prev.end = segment.end;
+                prev.text.push(' ');
+                prev.text.push_str(&segment.text);
+            }
+            _ => merged.push(segment),
+        }
+    }
+
+    DiarizationResult { segments: merged }
+}
@@ -0,0 +1,91 @@
+use super::transcribe::TranscriptionResult;
+
+fn srt_timestamp(seconds: f64) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let ms = total_ms % 1000;
+    let total_seconds = total_ms / 1000;
+    let secs = total_seconds % 60;
+    let mins = (total_seconds / 60) % 60;
+    let hours = total_seconds / 3600;
+    format!("{:02}:{:02}:{:02},{:03}", hours, mins, secs, ms)
+}
+
+fn vtt_timestamp(seconds: f64) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let ms = total_ms % 1000;
+    let total_seconds = total_ms / 1000;
+    let secs = total_seconds % 60;
+    let mins = (total_seconds / 60) % 60;
+    let hours = total_seconds / 3600;
+    format!("{:02}:{:02}:{:02}.{:03}", hours, mins, secs, ms)
+}
+
+/// Formats a diarized transcript as SRT, one cue per segment, prefixing the speaker
+/// label when present so subtitle players that don't render speaker metadata still
+/// show who's talking.
+fn format_speaker_srt(result: &TranscriptionResult) -> String {
+    let mut out = String::new();
+    for (i, segment) in result.segments.iter().enumerate() {
+        let text = match &segment.speaker {
+            Some(speaker) => format!("{}: {}", speaker, segment.text),
+            None => segment.text.clone(),
+        };
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            srt_timestamp(segment.start),
+            srt_timestamp(segment.end),
+            text
+        ));
+    }
+    out
+}
+
+#[tauri::command]
+pub fn export_speaker_srt(result: TranscriptionResult, output_path: String) -> Result<(), String> {
+    std::fs::write(&output_path, format_speaker_srt(&result)).map_err(|e| e.to_string())
+}
+
+fn export_vtt(result: &TranscriptionResult) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for segment in &result.segments {
+        let text = match &segment.speaker {
+            Some(speaker) => format!("{}: {}", speaker, segment.text),
+            None => segment.text.clone(),
+        };
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            vtt_timestamp(segment.start),
+            vtt_timestamp(segment.end),
+            text
+        ));
+    }
+    out
+}
+
+fn export_txt(result: &TranscriptionResult) -> String {
+    result
+        .segments
+        .iter()
+        .map(|s| s.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes `result` to `output_path` in `format` ("vtt", "txt", or "json"), formatting
+/// directly from TranscriptionResult.segments in Rust — no Python round-trip needed for
+/// any of these, unlike the whisper-backed transcription itself.
+#[tauri::command]
+pub fn export_transcript(
+    result: TranscriptionResult,
+    format: String,
+    output_path: String,
+) -> Result<(), String> {
+    let contents = match format.as_str() {
+        "vtt" => export_vtt(&result),
+        "txt" => export_txt(&result),
+        "json" => serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?,
+        other => return Err(format!("unsupported export format: {}", other)),
+    };
+    std::fs::write(&output_path, contents).map_err(|e| e.to_string())
+}
@@ -0,0 +1,12 @@
+pub mod denoise;
+pub mod diarization;
+pub mod export;
+pub mod model;
+pub mod python_bridge;
+pub mod transcribe;
+
+pub use denoise::denoise_audio_ffmpeg;
+pub use diarization::merge_speaker_segments;
+pub use export::{export_speaker_srt, export_transcript};
+pub use model::{cancel_model_download, download_whisper_model};
+pub use transcribe::transcribe_audio;
@@ -0,0 +1,120 @@
+use serde::Serialize;
+use std::io::Read;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::AppHandle;
+
+use super::python_bridge;
+
+/// Set by `cancel_model_download` and polled on a timer in `download_whisper_model`
+/// while the child process runs, mirroring the `CANCEL_PULL` flag `ollama::commands`
+/// uses for model pulls.
+static CANCEL_DOWNLOAD: AtomicBool = AtomicBool::new(false);
+
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DownloadResult {
+    pub success: bool,
+    pub model: Option<String>,
+    pub error: Option<String>,
+}
+
+#[tauri::command]
+pub fn cancel_model_download() {
+    CANCEL_DOWNLOAD.store(true, Ordering::SeqCst);
+}
+
+/// Downloads a whisper model via audio_helper.py, polling `CANCEL_DOWNLOAD` on a timer
+/// and killing the child process the moment cancellation is requested, rather than
+/// letting the flag sit unread for the whole blocking call.
+#[tauri::command]
+pub fn download_whisper_model(app: AppHandle, model: String) -> Result<DownloadResult, String> {
+    CANCEL_DOWNLOAD.store(false, Ordering::SeqCst);
+
+    let script = python_bridge::helper_script_path(&app)?;
+    let args = serde_json::json!({
+        "action": "download_model",
+        "model": model,
+    })
+    .to_string();
+
+    let mut child = std::process::Command::new("python3")
+        .arg(&script)
+        .arg(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    // whisper's downloader writes frequent tqdm progress updates to stderr; for a
+    // multi-hundred-MB model those fill the OS pipe buffer well before the download
+    // finishes. Drain both pipes on dedicated reader threads while polling, instead of
+    // reading only after try_wait reports an exit, or the child blocks on write() and
+    // try_wait never reports one.
+    let stdout_buf = Arc::new(Mutex::new(String::new()));
+    let stdout_handle = child.stdout.take().map(|mut out| {
+        let stdout_buf = Arc::clone(&stdout_buf);
+        std::thread::spawn(move || {
+            let mut buf = String::new();
+            if out.read_to_string(&mut buf).is_ok() {
+                *stdout_buf.lock().unwrap() = buf;
+            }
+        })
+    });
+
+    let stderr_buf = Arc::new(Mutex::new(String::new()));
+    let stderr_handle = child.stderr.take().map(|mut err| {
+        let stderr_buf = Arc::clone(&stderr_buf);
+        std::thread::spawn(move || {
+            let mut buf = String::new();
+            if err.read_to_string(&mut buf).is_ok() {
+                *stderr_buf.lock().unwrap() = buf;
+            }
+        })
+    });
+
+    let status = loop {
+        if CANCEL_DOWNLOAD.load(Ordering::SeqCst) {
+            let _ = child.kill();
+            return Ok(DownloadResult {
+                success: false,
+                model: None,
+                error: Some("cancelled".to_string()),
+            });
+        }
+
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(status) => break status,
+            None => std::thread::sleep(CANCEL_POLL_INTERVAL),
+        }
+    };
+
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    if let Some(handle) = stderr_handle {
+        let _ = handle.join();
+    }
+
+    if !status.success() {
+        let stderr = stderr_buf.lock().unwrap().clone();
+        return Ok(DownloadResult {
+            success: false,
+            model: None,
+            error: Some(if stderr.trim().is_empty() {
+                format!("whisper model download exited with {}", status)
+            } else {
+                stderr.trim().to_string()
+            }),
+        });
+    }
+
+    Ok(DownloadResult {
+        success: true,
+        model: Some(model),
+        error: None,
+    })
+}
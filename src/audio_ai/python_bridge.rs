@@ -0,0 +1,152 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, Window};
+
+use crate::file_helpers::job_registry;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(150);
+
+pub fn helper_script_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .resolve_resource("resources/audio_helper.py")
+        .ok_or_else(|| "audio_helper.py resource not found".to_string())
+}
+
+/// Unique id for a job registered with the shared job_registry. Not reusing
+/// job_registry::new_job_id() since its ids are prefixed "media-".
+pub fn new_audio_job_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("audio-{}", nanos)
+}
+
+/// Runs the bundled audio_helper.py with the given JSON args and parses its stdout as
+/// JSON. Blocks until the process exits, which is fine for quick operations but gives
+/// no feedback for long transcriptions — those should use
+/// run_python_audio_command_streaming instead.
+pub fn run_python_audio_command(app: &AppHandle, args: &Value) -> Result<Value, String> {
+    let script = helper_script_path(app)?;
+    let output = Command::new("python3")
+        .arg(&script)
+        .arg(args.to_string())
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TranscriptionProgressPayload {
+    percent: f64,
+    partial_text: String,
+}
+
+/// Like run_python_audio_command, but spawns the helper under `job_id` in the shared
+/// job registry (so a cancel can find and kill it) and reads stderr line-by-line for
+/// `PROGRESS|<percent>|<partial text>` lines the Python side prints as each segment
+/// finishes, emitting a transcription-progress event for each one. The final parsed
+/// JSON on stdout is still returned once the process exits.
+pub fn run_python_audio_command_streaming(
+    app: &AppHandle,
+    window: &Window,
+    job_id: &str,
+    args: &Value,
+) -> Result<Value, String> {
+    let script = helper_script_path(app)?;
+    let mut child = Command::new("python3")
+        .arg(&script)
+        .arg(args.to_string())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let stdout_buf = Arc::new(Mutex::new(Vec::new()));
+    let stdout_handle = child.stdout.take().map(|mut stdout| {
+        let stdout_buf = Arc::clone(&stdout_buf);
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            if stdout.read_to_end(&mut buf).is_ok() {
+                *stdout_buf.lock().unwrap() = buf;
+            }
+        })
+    });
+
+    let stderr_buf = Arc::new(Mutex::new(String::new()));
+    let stderr_handle = child.stderr.take().map(|stderr| {
+        let window = window.clone();
+        let stderr_buf = Arc::clone(&stderr_buf);
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().flatten() {
+                {
+                    let mut captured = stderr_buf.lock().unwrap();
+                    captured.push_str(&line);
+                    captured.push('\n');
+                }
+
+                let Some(rest) = line.strip_prefix("PROGRESS|") else {
+                    continue;
+                };
+                let mut parts = rest.splitn(2, '|');
+                let Some(percent) = parts.next().and_then(|p| p.parse::<f64>().ok()) else {
+                    continue;
+                };
+                let partial_text = parts.next().unwrap_or("").to_string();
+                let _ = window.emit(
+                    "transcription-progress",
+                    TranscriptionProgressPayload {
+                        percent: percent.clamp(0.0, 100.0),
+                        partial_text,
+                    },
+                );
+            }
+        })
+    });
+
+    job_registry::register(job_id.to_string(), child);
+
+    let status = loop {
+        match job_registry::try_wait(job_id) {
+            Ok(Some(status)) => break status,
+            Ok(None) => {
+                std::thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            Err(_) => return Err(format!("audio job {} was cancelled", job_id)),
+        }
+    };
+    job_registry::unregister(job_id);
+
+    // Wait for the reader threads to finish draining the pipes before reading the
+    // buffers they fill — the child exiting doesn't mean the threads have caught up,
+    // and reading early can race a partially-filled buffer into a spurious failure.
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    if let Some(handle) = stderr_handle {
+        let _ = handle.join();
+    }
+
+    if !status.success() {
+        let captured = stderr_buf.lock().unwrap().clone();
+        return Err(if captured.is_empty() {
+            format!("audio helper exited with {}", status)
+        } else {
+            captured
+        });
+    }
+
+    let stdout_bytes = stdout_buf.lock().unwrap().clone();
+    serde_json::from_slice(&stdout_bytes).map_err(|e| e.to_string())
+}
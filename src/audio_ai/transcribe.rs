@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Window};
+
+use super::python_bridge;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    pub speaker: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionResult {
+    pub segments: Vec<TranscriptSegment>,
+    pub language: Option<String>,
+    pub duration_seconds: f64,
+}
+
+/// Transcribes `audio_path` with the bundled whisper-backed audio_helper.py, streaming
+/// transcription-progress events (percent and the latest segment's text) as it goes
+/// rather than blocking silently through a long recording.
+#[tauri::command]
+pub fn transcribe_audio(
+    app: AppHandle,
+    window: Window,
+    audio_path: String,
+    model: Option<String>,
+) -> Result<TranscriptionResult, String> {
+    let job_id = python_bridge::new_audio_job_id();
+    let args = serde_json::json!({
+        "action": "transcribe",
+        "audio_path": audio_path,
+        "model": model.unwrap_or_else(|| "base".to_string()),
+    });
+    let value = python_bridge::run_python_audio_command_streaming(&app, &window, &job_id, &args)?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
@@ -0,0 +1,502 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WindowInfo {
+    pub title: Option<String>,
+    pub process_name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_active_window_info() -> WindowInfo {
+    use std::os::windows::prelude::OsStringExt;
+    use winapi::um::processthreadsapi::OpenProcess;
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::psapi::GetModuleBaseNameW;
+    use winapi::um::winnt::{PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+    use winapi::um::winuser::{GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_null() {
+            return WindowInfo::default();
+        }
+
+        let len = GetWindowTextLengthW(hwnd);
+        let title = if len > 0 {
+            let mut buf: Vec<u16> = vec![0; (len + 1) as usize];
+            let copied = GetWindowTextW(hwnd, buf.as_mut_ptr(), len + 1);
+            if copied > 0 {
+                Some(std::ffi::OsString::from_wide(&buf[..copied as usize]).to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let mut pid: u32 = 0;
+        GetWindowThreadProcessId(hwnd, &mut pid);
+        let process_name = if pid != 0 {
+            let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, 0, pid);
+            if handle.is_null() {
+                None
+            } else {
+                let mut buf: Vec<u16> = vec![0; 260];
+                let copied = GetModuleBaseNameW(handle, std::ptr::null_mut(), buf.as_mut_ptr(), buf.len() as u32);
+                CloseHandle(handle);
+                if copied > 0 {
+                    Some(std::ffi::OsString::from_wide(&buf[..copied as usize]).to_string_lossy().into_owned())
+                } else {
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let (x, y, width, height) = get_foreground_window_rect().unwrap_or((0, 0, 0, 0));
+
+        WindowInfo {
+            title,
+            process_name,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_active_window_info() -> WindowInfo {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    let result = (|| -> Result<WindowInfo, Box<dyn std::error::Error>> {
+        let (conn, screen_num) = x11rb::connect(None)?;
+        let root = conn.setup().roots[screen_num].root;
+
+        let net_active_window = conn.intern_atom(false, b"_NET_ACTIVE_WINDOW")?.reply()?.atom;
+        let prop = conn
+            .get_property(false, root, net_active_window, AtomEnum::WINDOW, 0, 1)?
+            .reply()?;
+        let window = prop
+            .value32()
+            .and_then(|mut v| v.next())
+            .ok_or("no active window")?;
+
+        let (x, y, width, height) = get_foreground_window_rect().unwrap_or((0, 0, 0, 0));
+
+        let net_wm_name = conn.intern_atom(false, b"_NET_WM_NAME")?.reply()?.atom;
+        let utf8_string = conn.intern_atom(false, b"UTF8_STRING")?.reply()?.atom;
+        let name_prop = conn.get_property(false, window, net_wm_name, utf8_string, 0, u32::MAX)?.reply()?;
+        let title = if name_prop.value.is_empty() {
+            None
+        } else {
+            Some(String::from_utf8_lossy(&name_prop.value).into_owned())
+        };
+
+        Ok(WindowInfo {
+            title,
+            process_name: None,
+            x,
+            y,
+            width,
+            height,
+        })
+    })();
+
+    result.unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_foreground_window_rect() -> Option<(i32, i32, i32, i32)> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    (|| -> Result<(i32, i32, i32, i32), Box<dyn std::error::Error>> {
+        let (conn, screen_num) = x11rb::connect(None)?;
+        let root = conn.setup().roots[screen_num].root;
+
+        let net_active_window = conn.intern_atom(false, b"_NET_ACTIVE_WINDOW")?.reply()?.atom;
+        let prop = conn
+            .get_property(false, root, net_active_window, AtomEnum::WINDOW, 0, 1)?
+            .reply()?;
+        let window = prop
+            .value32()
+            .and_then(|mut v| v.next())
+            .ok_or("no active window")?;
+
+        let geometry = conn.get_geometry(window)?.reply()?;
+        let translated = conn
+            .translate_coordinates(window, root, geometry.x, geometry.y)?
+            .reply()?;
+
+        Ok((
+            translated.dst_x as i32,
+            translated.dst_y as i32,
+            geometry.width as i32,
+            geometry.height as i32,
+        ))
+    })()
+    .ok()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn get_active_window_info() -> WindowInfo {
+    WindowInfo::default()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn get_foreground_window_rect() -> Option<(i32, i32, i32, i32)> {
+    None
+}
+
+#[tauri::command]
+pub fn get_active_window() -> WindowInfo {
+    get_active_window_info()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_foreground_window_rect() -> Option<(i32, i32, i32, i32)> {
+    use winapi::shared::windef::RECT;
+    use winapi::um::winuser::{GetForegroundWindow, GetWindowRect};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_null() {
+            return None;
+        }
+        let mut rect: RECT = std::mem::zeroed();
+        if GetWindowRect(hwnd, &mut rect) == 0 {
+            return None;
+        }
+        Some((rect.left, rect.top, rect.right - rect.left, rect.bottom - rect.top))
+    }
+}
+
+#[cfg(target_os = "linux")]
+const X11_SELECTION_TIMEOUT_MS: u64 = 500;
+
+/// Performs a proper ICCCM selection round trip: request PRIMARY as UTF8_STRING on a
+/// hidden window, wait for SelectionNotify, then read the property (handling the INCR
+/// protocol for selections too large to transfer in one go). Returns `Ok(None)` on
+/// timeout or if nothing is selected, never hangs the command.
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub fn get_selected_text() -> Result<Option<String>, String> {
+    use std::time::{Duration, Instant};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, CreateWindowAux, EventMask, WindowClass};
+    use x11rb::protocol::Event;
+
+    let (conn, screen_num) = x11rb::connect(None).map_err(|e| e.to_string())?;
+    let screen = conn.setup().roots[screen_num].clone();
+
+    let window = conn.generate_id().map_err(|e| e.to_string())?;
+    conn.create_window(
+        screen.root_depth,
+        window,
+        screen.root,
+        0,
+        0,
+        1,
+        1,
+        0,
+        WindowClass::INPUT_OUTPUT,
+        screen.root_visual,
+        &CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let primary: u32 = AtomEnum::PRIMARY.into();
+    let utf8_string = conn
+        .intern_atom(false, b"UTF8_STRING")
+        .map_err(|e| e.to_string())?
+        .reply()
+        .map_err(|e| e.to_string())?
+        .atom;
+    let incr_atom = conn
+        .intern_atom(false, b"INCR")
+        .map_err(|e| e.to_string())?
+        .reply()
+        .map_err(|e| e.to_string())?
+        .atom;
+    let target_prop = conn
+        .intern_atom(false, b"ASKOCR_SELECTION")
+        .map_err(|e| e.to_string())?
+        .reply()
+        .map_err(|e| e.to_string())?
+        .atom;
+
+    conn.convert_selection(window, primary, utf8_string, target_prop, x11rb::CURRENT_TIME)
+        .map_err(|e| e.to_string())?;
+    conn.flush().map_err(|e| e.to_string())?;
+
+    let deadline = Instant::now() + Duration::from_millis(X11_SELECTION_TIMEOUT_MS);
+    let result = loop {
+        if Instant::now() > deadline {
+            break Ok(None);
+        }
+        match conn.poll_for_event().map_err(|e| e.to_string())? {
+            Some(Event::SelectionNotify(notify)) => {
+                if notify.property == x11rb::NONE {
+                    break Ok(None);
+                }
+                break read_selection_property(&conn, window, target_prop, utf8_string, incr_atom, deadline);
+            }
+            Some(_) => continue,
+            None => std::thread::sleep(Duration::from_millis(10)),
+        }
+    };
+
+    let _ = conn.destroy_window(window);
+    let _ = conn.flush();
+    result
+}
+
+#[cfg(target_os = "linux")]
+fn read_selection_property(
+    conn: &impl x11rb::connection::Connection,
+    window: u32,
+    property: u32,
+    utf8_string: u32,
+    incr_atom: u32,
+    deadline: std::time::Instant,
+) -> Result<Option<String>, String> {
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, Property};
+    use x11rb::protocol::Event;
+
+    let reply = conn
+        .get_property(false, window, property, AtomEnum::ANY, 0, u32::MAX)
+        .map_err(|e| e.to_string())?
+        .reply()
+        .map_err(|e| e.to_string())?;
+
+    if reply.type_ != incr_atom {
+        let _ = conn.delete_property(window, property);
+        let _ = conn.flush();
+        if reply.value.is_empty() || reply.type_ != utf8_string {
+            return Ok(None);
+        }
+        return Ok(Some(String::from_utf8_lossy(&reply.value).into_owned()));
+    }
+
+    // INCR transfer: the owner writes successive chunks and clears the property;
+    // an empty chunk signals completion.
+    let _ = conn.delete_property(window, property);
+    let _ = conn.flush();
+    let mut collected = Vec::new();
+    loop {
+        if std::time::Instant::now() > deadline {
+            return Ok(None);
+        }
+        match conn.poll_for_event().map_err(|e| e.to_string())? {
+            Some(Event::PropertyNotify(ev)) if ev.state == Property::NEW_VALUE => {
+                let chunk = conn
+                    .get_property(false, window, property, AtomEnum::ANY, 0, u32::MAX)
+                    .map_err(|e| e.to_string())?
+                    .reply()
+                    .map_err(|e| e.to_string())?;
+                let _ = conn.delete_property(window, property);
+                let _ = conn.flush();
+                if chunk.value.is_empty() {
+                    return Ok(Some(String::from_utf8_lossy(&collected).into_owned()));
+                }
+                collected.extend_from_slice(&chunk.value);
+            }
+            Some(_) => continue,
+            None => std::thread::sleep(std::time::Duration::from_millis(10)),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub fn get_selected_text() -> Result<Option<String>, String> {
+    Ok(None)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FileExplorerContext {
+    pub current_path: String,
+    pub selected_files: Vec<String>,
+}
+
+#[cfg(target_os = "macos")]
+fn execute_applescript(script: &str) -> Result<String, String> {
+    let output = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// AppleScript errors (e.g. no Finder window open) are treated as "nothing to report"
+/// rather than a hard error, since this is best-effort context for the AI assistant.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+pub fn get_file_explorer_context() -> FileExplorerContext {
+    let current_path = execute_applescript(
+        "tell application \"Finder\" to get POSIX path of (target of front window as alias)",
+    )
+    .unwrap_or_default();
+
+    let selected_raw = execute_applescript(
+        "tell application \"Finder\" to get POSIX path of (selection as alias list)",
+    )
+    .unwrap_or_default();
+    let selected_files: Vec<String> = selected_raw
+        .split(", ")
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    FileExplorerContext {
+        current_path,
+        selected_files,
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn get_file_explorer_context() -> FileExplorerContext {
+    FileExplorerContext::default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BrowserContext {
+    pub url: Option<String>,
+    pub title: Option<String>,
+}
+
+const CHROMIUM_PROCESSES: &[(&str, &str)] = &[
+    ("chrome.exe", " - Google Chrome"),
+    ("msedge.exe", " - Microsoft Edge"),
+    ("brave.exe", " - Brave"),
+];
+
+/// Reads the address bar of the foreground Chromium window via UI Automation.
+/// Returns an empty context (not an error) if UIA or the control isn't available,
+/// since this is best-effort context for the AI assistant, not a critical path.
+#[cfg(target_os = "windows")]
+#[tauri::command]
+pub fn get_browser_context() -> BrowserContext {
+    use uiautomation::controls::ControlType;
+    use uiautomation::UIAutomation;
+
+    let info = get_active_window_info();
+    let Some(process_name) = info.process_name.as_deref() else {
+        return BrowserContext::default();
+    };
+    let Some((_, suffix)) = CHROMIUM_PROCESSES.iter().find(|(p, _)| p.eq_ignore_ascii_case(process_name)) else {
+        return BrowserContext::default();
+    };
+
+    let title = info.title.clone().map(|t| t.trim_end_matches(suffix).to_string());
+
+    let url = (|| -> Option<String> {
+        let automation = UIAutomation::new().ok()?;
+        let hwnd = unsafe { winapi::um::winuser::GetForegroundWindow() };
+        if hwnd.is_null() {
+            return None;
+        }
+        let window = automation.element_from_handle((hwnd as isize).into()).ok()?;
+        let matcher = automation
+            .create_matcher()
+            .from(window)
+            .control_type(ControlType::Edit)
+            .timeout(500);
+        let edit = matcher.find_first().ok()?;
+        let value = edit.get_pattern::<uiautomation::patterns::UIValuePattern>().ok()?.get_value().ok()?;
+        if value.is_empty() {
+            None
+        } else {
+            Some(value)
+        }
+    })();
+
+    BrowserContext { url, title }
+}
+
+#[cfg(not(target_os = "windows"))]
+#[tauri::command]
+pub fn get_browser_context() -> BrowserContext {
+    BrowserContext::default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EditorContext {
+    pub file_name: Option<String>,
+    pub project_path: Option<String>,
+    pub file_path: Option<String>,
+    pub language: Option<String>,
+    pub unsaved: bool,
+}
+
+const EXTENSION_LANGUAGES: &[(&str, &str)] = &[
+    ("rs", "rust"),
+    ("ts", "typescript"),
+    ("tsx", "typescriptreact"),
+    ("js", "javascript"),
+    ("jsx", "javascriptreact"),
+    ("py", "python"),
+    ("go", "go"),
+    ("java", "java"),
+    ("c", "c"),
+    ("cpp", "cpp"),
+    ("json", "json"),
+    ("md", "markdown"),
+];
+
+fn infer_language(file_name: &str) -> Option<String> {
+    let ext = file_name.rsplit('.').next()?;
+    EXTENSION_LANGUAGES
+        .iter()
+        .find(|(e, _)| e.eq_ignore_ascii_case(ext))
+        .map(|(_, lang)| lang.to_string())
+}
+
+/// VS Code's window title is reliably `"<file> - <folder> - Visual Studio Code"`
+/// (optionally prefixed with "●" for unsaved changes and suffixed with "[Administrator]").
+#[tauri::command]
+pub fn get_editor_context() -> EditorContext {
+    let info = get_active_window_info();
+    let Some(process_name) = info.process_name.as_deref() else {
+        return EditorContext::default();
+    };
+    if !process_name.eq_ignore_ascii_case("Code.exe") {
+        return EditorContext::default();
+    }
+    let Some(title) = info.title else {
+        return EditorContext::default();
+    };
+
+    let title = title.trim_end_matches("[Administrator]").trim();
+    let unsaved = title.starts_with('●');
+    let title = title.trim_start_matches('●').trim();
+
+    let parts: Vec<&str> = title.split(" - ").collect();
+    let file_part = parts.first().copied().unwrap_or_default().trim();
+    let project_part = if parts.len() >= 3 { Some(parts[parts.len() - 2].trim().to_string()) } else { None };
+
+    if file_part.is_empty() || file_part.eq_ignore_ascii_case("Visual Studio Code") {
+        return EditorContext::default();
+    }
+
+    EditorContext {
+        file_name: Some(file_part.to_string()),
+        language: infer_language(file_part),
+        project_path: project_part.clone(),
+        file_path: project_part.map(|p| format!("{}/{}", p, file_part)),
+        unsaved,
+    }
+}
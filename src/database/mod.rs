@@ -0,0 +1,1151 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+const CURRENT_SCHEMA_VERSION: i64 = 9;
+
+pub struct Database {
+    pub conn: Mutex<Connection>,
+    pub history_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRecord {
+    pub id: Option<i64>,
+    pub model_type: String,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Playlist {
+    pub id: Option<i64>,
+    pub name: String,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Song {
+    pub id: Option<i64>,
+    pub title: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub file_path: String,
+    pub added_at: i64,
+    pub play_count: i64,
+    pub last_played_at: Option<i64>,
+    pub content_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Setting {
+    pub key: String,
+    pub value: String,
+    pub value_type: String,
+    pub category: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrRecord {
+    pub id: Option<i64>,
+    pub timestamp: i64,
+    pub image_data: Option<String>,
+    pub image_path: Option<String>,
+    pub text: String,
+    pub summary: Option<String>,
+    pub tags: Option<String>,
+    pub ai_answers: Option<String>,
+    pub language: Option<String>,
+    pub confidence: f64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    /// How long recognition took, in milliseconds. Set by the caller (the OCR command
+    /// measures its own elapsed time) rather than computed here, since the database
+    /// layer never touches the recognizer.
+    pub processing_time_ms: Option<i64>,
+}
+
+impl Database {
+    pub fn new(path: &str) -> Result<Self, String> {
+        let conn = Connection::open(path).map_err(|e| e.to_string())?;
+        let journal_mode: String = conn
+            .query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))
+            .map_err(|e| e.to_string())?;
+        conn.execute_batch("PRAGMA busy_timeout = 5000;").map_err(|e| e.to_string())?;
+        eprintln!("database journal mode: {}", journal_mode);
+
+        let history_dir = PathBuf::from(path)
+            .parent()
+            .map(|p| p.join("history"))
+            .unwrap_or_else(|| PathBuf::from("history"));
+        std::fs::create_dir_all(&history_dir).map_err(|e| e.to_string())?;
+        let db = Database {
+            conn: Mutex::new(conn),
+            history_dir,
+        };
+        db.run_migrations()?;
+        Ok(db)
+    }
+
+    fn run_migrations(&self) -> Result<(), String> {
+        let conn = self.conn.lock().map_err(|e| e.to_string())?;
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|e| e.to_string())?;
+
+        if version < 1 {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS ocr_record (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    timestamp INTEGER NOT NULL,
+                    image_data TEXT,
+                    image_path TEXT,
+                    text TEXT NOT NULL,
+                    summary TEXT,
+                    tags TEXT,
+                    ai_answers TEXT,
+                    language TEXT,
+                    confidence REAL NOT NULL DEFAULT 1.0,
+                    created_at INTEGER NOT NULL,
+                    updated_at INTEGER NOT NULL
+                );
+                PRAGMA user_version = 1;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 2 {
+            conn.execute_batch(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS ocr_record_fts USING fts5(
+                    text, summary, tags, content='ocr_record', content_rowid='id'
+                );
+                INSERT INTO ocr_record_fts(rowid, text, summary, tags)
+                    SELECT id, text, summary, tags FROM ocr_record;
+                PRAGMA user_version = 2;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 3 {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS model_record (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    model_type TEXT NOT NULL,
+                    name TEXT NOT NULL,
+                    is_active INTEGER NOT NULL DEFAULT 0,
+                    created_at INTEGER NOT NULL
+                );
+                PRAGMA user_version = 3;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 4 {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS playlists (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    name TEXT NOT NULL,
+                    created_at INTEGER NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS songs (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    title TEXT NOT NULL,
+                    artist TEXT,
+                    album TEXT,
+                    file_path TEXT NOT NULL,
+                    added_at INTEGER NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS playlist_songs (
+                    playlist_id INTEGER NOT NULL,
+                    song_id INTEGER NOT NULL,
+                    PRIMARY KEY (playlist_id, song_id)
+                );
+                PRAGMA user_version = 4;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 5 {
+            conn.execute_batch(
+                "ALTER TABLE playlist_songs ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+                PRAGMA user_version = 5;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 6 {
+            conn.execute_batch(
+                "ALTER TABLE songs ADD COLUMN play_count INTEGER NOT NULL DEFAULT 0;
+                ALTER TABLE songs ADD COLUMN last_played_at INTEGER;
+                PRAGMA user_version = 6;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 7 {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS settings (
+                    key TEXT PRIMARY KEY,
+                    value TEXT NOT NULL,
+                    value_type TEXT NOT NULL,
+                    category TEXT NOT NULL
+                );
+                PRAGMA user_version = 7;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 8 {
+            conn.execute_batch(
+                "ALTER TABLE songs ADD COLUMN content_hash TEXT;
+                CREATE UNIQUE INDEX IF NOT EXISTS idx_songs_content_hash
+                    ON songs(content_hash) WHERE content_hash IS NOT NULL;
+                PRAGMA user_version = 8;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        if version < 9 {
+            conn.execute_batch(
+                "ALTER TABLE ocr_record ADD COLUMN processing_time_ms INTEGER;
+                PRAGMA user_version = 9;",
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        seed_default_settings(&conn)?;
+
+        let _ = CURRENT_SCHEMA_VERSION;
+        Ok(())
+    }
+}
+
+const DEFAULT_SETTINGS: &[(&str, &str, &str, &str)] = &[
+    ("auto_copy_ocr", "false", "bool", "ocr"),
+    ("auto_start_ollama", "false", "bool", "ollama"),
+    ("history_retention_days", "0", "int", "general"),
+    ("ocr_language", "en", "string", "ocr"),
+    ("offline_mode", "false", "bool", "general"),
+    ("ollama_keep_alive", "5m", "string", "ollama"),
+    ("screenshot_format", "png", "string", "screenshot"),
+    ("theme", "system", "string", "appearance"),
+    ("tray_notifications", "true", "bool", "tray"),
+];
+
+fn seed_default_settings(conn: &Connection) -> Result<(), String> {
+    for (key, value, value_type, category) in DEFAULT_SETTINGS {
+        conn.execute(
+            "INSERT OR IGNORE INTO settings (key, value, value_type, category) VALUES (?1, ?2, ?3, ?4)",
+            params![key, value, value_type, category],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_setting(state: State<Database>, key: String) -> Result<Option<Setting>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.query_row(
+        "SELECT key, value, value_type, category FROM settings WHERE key = ?1",
+        params![key],
+        |row| {
+            Ok(Setting {
+                key: row.get(0)?,
+                value: row.get(1)?,
+                value_type: row.get(2)?,
+                category: row.get(3)?,
+            })
+        },
+    )
+    .map(Some)
+    .or_else(|e| if e == rusqlite::Error::QueryReturnedNoRows { Ok(None) } else { Err(e.to_string()) })
+}
+
+#[tauri::command]
+pub fn get_setting_typed(state: State<Database>, key: String) -> Result<serde_json::Value, String> {
+    let setting = get_setting(state, key.clone())?
+        .ok_or_else(|| format!("setting '{}' does not exist", key))?;
+    match setting.value_type.as_str() {
+        "bool" => setting
+            .value
+            .parse::<bool>()
+            .map(serde_json::Value::Bool)
+            .map_err(|_| format!("setting '{}' is not a valid bool", key)),
+        "int" => setting
+            .value
+            .parse::<i64>()
+            .map(|v| serde_json::Value::Number(v.into()))
+            .map_err(|_| format!("setting '{}' is not a valid int", key)),
+        "float" => setting
+            .value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number)
+            .ok_or_else(|| format!("setting '{}' is not a valid float", key)),
+        "json" => serde_json::from_str(&setting.value).map_err(|e| format!("setting '{}' is not valid json: {}", key, e)),
+        "string" => Ok(serde_json::Value::String(setting.value)),
+        other => Err(format!("setting '{}' has unknown value_type '{}'", key, other)),
+    }
+}
+
+#[tauri::command]
+pub fn set_setting(state: State<Database>, key: String, value: String, value_type: String, category: String) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO settings (key, value, value_type, category) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value, value_type = excluded.value_type, category = excluded.category",
+        params![key, value, value_type, category],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Writes every setting to a JSON file, so users reinstalling or moving machines can
+/// carry their configuration over instead of reconfiguring from scratch.
+#[tauri::command]
+pub fn export_settings(state: State<Database>, path: String) -> Result<usize, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare("SELECT key, value, value_type, category FROM settings ORDER BY key")
+        .map_err(|e| e.to_string())?;
+    let settings: Vec<Setting> = stmt
+        .query_map([], |row| {
+            Ok(Setting {
+                key: row.get(0)?,
+                value: row.get(1)?,
+                value_type: row.get(2)?,
+                category: row.get(3)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| e.to_string())?;
+    Ok(settings.len())
+}
+
+/// Reads a settings JSON file written by `export_settings` back into the database.
+/// Malformed entries (missing fields, wrong types) are skipped rather than failing the
+/// whole import, and reported back in `skipped` so the caller can tell the user. When
+/// `overwrite` is true, existing settings are replaced; otherwise they're left alone and
+/// only new keys are added.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportSettingsResult {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+#[tauri::command]
+pub fn import_settings(state: State<Database>, path: String, overwrite: bool) -> Result<ImportSettingsResult, String> {
+    let contents = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let raw: serde_json::Value = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+    let entries = raw.as_array().ok_or_else(|| "settings file must contain a JSON array".to_string())?;
+
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let sql = if overwrite {
+        "INSERT OR REPLACE INTO settings (key, value, value_type, category) VALUES (?1, ?2, ?3, ?4)"
+    } else {
+        "INSERT OR IGNORE INTO settings (key, value, value_type, category) VALUES (?1, ?2, ?3, ?4)"
+    };
+
+    let mut imported = 0;
+    let mut skipped = 0;
+    for entry in entries {
+        match serde_json::from_value::<Setting>(entry.clone()) {
+            Ok(setting) => {
+                let rows_changed = conn
+                    .execute(sql, params![setting.key, setting.value, setting.value_type, setting.category])
+                    .map_err(|e| e.to_string())?;
+                if rows_changed == 1 {
+                    imported += 1;
+                } else {
+                    skipped += 1;
+                }
+            }
+            Err(_) => skipped += 1,
+        }
+    }
+
+    Ok(ImportSettingsResult { imported, skipped })
+}
+
+fn write_history_image(history_dir: &std::path::Path, timestamp: i64, data_url: &str) -> Result<String, String> {
+    let b64 = data_url.split(',').last().unwrap_or(data_url);
+    let bytes = STANDARD.decode(b64).map_err(|e| e.to_string())?;
+    let file_name = format!("{}_{}.png", timestamp, uuid_like());
+    let path = history_dir.join(file_name);
+    std::fs::write(&path, bytes).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+fn uuid_like() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("{:x}", nanos)
+}
+
+#[tauri::command]
+pub fn load_ocr_image(state: State<Database>, id: i64) -> Result<String, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let image_path: Option<String> = conn
+        .query_row("SELECT image_path FROM ocr_record WHERE id = ?1", params![id], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    let image_path = image_path.ok_or_else(|| format!("ocr_record {} has no saved image", id))?;
+    let bytes = std::fs::read(&image_path).map_err(|e| e.to_string())?;
+    Ok(format!("data:image/png;base64,{}", STANDARD.encode(bytes)))
+}
+
+fn fts_sync(conn: &Connection, id: i64, text: &str, summary: &Option<String>, tags: &Option<String>) -> Result<(), String> {
+    conn.execute("DELETE FROM ocr_record_fts WHERE rowid = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO ocr_record_fts(rowid, text, summary, tags) VALUES (?1, ?2, ?3, ?4)",
+        params![id, text, summary, tags],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+const OCR_RECORD_COLUMNS: &str =
+    "id, timestamp, image_data, image_path, text, summary, tags, ai_answers, language, confidence, created_at, updated_at, processing_time_ms";
+
+#[tauri::command]
+pub fn create_ocr_record(app: AppHandle, state: State<Database>, mut record: OcrRecord) -> Result<i64, String> {
+    // Write the image to the history folder and drop the inline base64 copy so the
+    // SQLite file doesn't balloon. If the write fails, keep image_data inline so the
+    // capture isn't lost.
+    if record.image_path.is_none() {
+        if let Some(data) = record.image_data.clone() {
+            match write_history_image(&state.history_dir, record.timestamp, &data) {
+                Ok(path) => {
+                    record.image_path = Some(path);
+                    record.image_data = None;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO ocr_record (timestamp, image_data, image_path, text, summary, tags, ai_answers, language, confidence, created_at, updated_at, processing_time_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            record.timestamp,
+            record.image_data,
+            record.image_path,
+            record.text,
+            record.summary,
+            record.tags,
+            record.ai_answers,
+            record.language,
+            record.confidence,
+            record.created_at,
+            record.updated_at,
+            record.processing_time_ms,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    let id = conn.last_insert_rowid();
+    record.id = Some(id);
+    fts_sync(&conn, id, &record.text, &record.summary, &record.tags)?;
+
+    let notifications_enabled: String = conn
+        .query_row(
+            "SELECT value FROM settings WHERE key = 'tray_notifications'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or_else(|_| "true".to_string());
+    drop(conn);
+    if notifications_enabled == "true" {
+        crate::tray::notify_capture_done(&app, &record.text);
+    }
+
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn get_all_ocr_records(state: State<Database>, limit: i64, offset: i64) -> Result<Vec<OcrRecord>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM ocr_record ORDER BY timestamp DESC LIMIT ?1 OFFSET ?2",
+            OCR_RECORD_COLUMNS
+        ))
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![limit, offset], row_to_record)
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_ocr_record(state: State<Database>, id: i64, record: OcrRecord) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "UPDATE ocr_record SET summary = ?1, tags = ?2, ai_answers = ?3, updated_at = ?4, text = ?5, language = ?6, confidence = ?7 WHERE id = ?8",
+        params![
+            record.summary,
+            record.tags,
+            record.ai_answers,
+            record.updated_at,
+            record.text,
+            record.language,
+            record.confidence,
+            id,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    fts_sync(&conn, id, &record.text, &record.summary, &record.tags)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn search_ocr_records(state: State<Database>, query: String, limit: Option<i64>) -> Result<Vec<OcrRecord>, String> {
+    let limit = limit.unwrap_or(50);
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+
+    if query.trim().is_empty() {
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {} FROM ocr_record ORDER BY timestamp DESC LIMIT ?1",
+                OCR_RECORD_COLUMNS
+            ))
+            .map_err(|e| e.to_string())?;
+        let rows = stmt.query_map(params![limit], row_to_record).map_err(|e| e.to_string())?;
+        return rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string());
+    }
+
+    let qualified_columns: String = OCR_RECORD_COLUMNS
+        .split(", ")
+        .map(|c| format!("r.{}", c))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM ocr_record_fts f
+             JOIN ocr_record r ON r.id = f.rowid
+             WHERE ocr_record_fts MATCH ?1
+             ORDER BY rank
+             LIMIT ?2",
+            qualified_columns
+        ))
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![query, limit], row_to_record)
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn export_ocr_records(
+    state: State<Database>,
+    _app: tauri::AppHandle,
+    format: String,
+    path: String,
+) -> Result<usize, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM ocr_record ORDER BY timestamp ASC",
+            OCR_RECORD_COLUMNS
+        ))
+        .map_err(|e| e.to_string())?;
+    let records: Vec<OcrRecord> = stmt
+        .query_map([], row_to_record)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    match format.as_str() {
+        "csv" => {
+            let mut out = String::from("id,timestamp,language,confidence,text\n");
+            for r in &records {
+                out.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    r.id.unwrap_or_default(),
+                    r.timestamp,
+                    csv_quote(r.language.as_deref().unwrap_or("")),
+                    r.confidence,
+                    csv_quote(&r.text),
+                ));
+            }
+            std::fs::write(&path, out).map_err(|e| e.to_string())?;
+        }
+        "json" => {
+            let out = serde_json::to_string_pretty(&records).map_err(|e| e.to_string())?;
+            std::fs::write(&path, out).map_err(|e| e.to_string())?;
+        }
+        other => return Err(format!("unsupported export format: {}", other)),
+    }
+
+    Ok(records.len())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageCount {
+    pub language: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyCount {
+    pub day: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OcrStats {
+    pub total_captures: i64,
+    pub avg_processing_time_ms: Option<f64>,
+    pub median_processing_time_ms: Option<f64>,
+    pub captures_per_language: Vec<LanguageCount>,
+    pub captures_per_day: Vec<DailyCount>,
+}
+
+/// Aggregates OCR history for a small usage dashboard: total captures, average/median
+/// recognition time, a per-language breakdown, and a daily count for the last 30 days.
+/// Median has no SQLite builtin, so it's computed in Rust from the same processing-time
+/// rows used for the average rather than running a second query for it.
+#[tauri::command]
+pub fn get_ocr_stats(state: State<Database>) -> Result<OcrStats, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+
+    let total_captures: i64 = conn
+        .query_row("SELECT COUNT(*) FROM ocr_record", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+
+    let mut times: Vec<i64> = conn
+        .prepare("SELECT processing_time_ms FROM ocr_record WHERE processing_time_ms IS NOT NULL")
+        .map_err(|e| e.to_string())?
+        .query_map([], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    times.sort_unstable();
+
+    let avg_processing_time_ms = if times.is_empty() {
+        None
+    } else {
+        Some(times.iter().sum::<i64>() as f64 / times.len() as f64)
+    };
+    let median_processing_time_ms = if times.is_empty() {
+        None
+    } else if times.len() % 2 == 0 {
+        let mid = times.len() / 2;
+        Some((times[mid - 1] + times[mid]) as f64 / 2.0)
+    } else {
+        Some(times[times.len() / 2] as f64)
+    };
+
+    let mut lang_stmt = conn
+        .prepare(
+            "SELECT COALESCE(language, 'unknown'), COUNT(*) FROM ocr_record
+             GROUP BY language ORDER BY COUNT(*) DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let captures_per_language = lang_stmt
+        .query_map([], |row| {
+            Ok(LanguageCount {
+                language: row.get(0)?,
+                count: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut day_stmt = conn
+        .prepare(
+            "SELECT date(timestamp / 1000, 'unixepoch') AS day, COUNT(*) FROM ocr_record
+             WHERE timestamp >= (strftime('%s', 'now', '-30 days') * 1000)
+             GROUP BY day ORDER BY day ASC",
+        )
+        .map_err(|e| e.to_string())?;
+    let captures_per_day = day_stmt
+        .query_map([], |row| {
+            Ok(DailyCount {
+                day: row.get(0)?,
+                count: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Ok(OcrStats {
+        total_captures,
+        avg_processing_time_ms,
+        median_processing_time_ms,
+        captures_per_language,
+        captures_per_day,
+    })
+}
+
+/// Canonical separator for the free-form `ocr_record.tags` column. Tags are stored as a
+/// single comma-separated string rather than a join table since a capture rarely has
+/// more than a handful of tags and the existing schema already leans on plain text
+/// columns (see `language`, `summary`) instead of normalization.
+const TAG_SEPARATOR: char = ',';
+
+fn split_tags(tags: &str) -> impl Iterator<Item = &str> {
+    tags.split(TAG_SEPARATOR).map(|t| t.trim()).filter(|t| !t.is_empty())
+}
+
+/// Lists every tag in use along with how many records carry it, so the history UI can
+/// query the database for its tag filter instead of pulling every record and counting
+/// tags client-side.
+#[tauri::command]
+pub fn get_all_tags(state: State<Database>) -> Result<Vec<(String, usize)>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare("SELECT tags FROM ocr_record WHERE tags IS NOT NULL AND tags != ''")
+        .map_err(|e| e.to_string())?;
+    let rows: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for tags in &rows {
+        for tag in split_tags(tags) {
+            *counts.entry(tag.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    let mut result: Vec<(String, usize)> = counts.into_iter().collect();
+    result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(result)
+}
+
+/// Returns records carrying the given tag, newest first. Matches on the comma-split tag
+/// list rather than a raw substring so a tag like "cat" doesn't also match "category".
+#[tauri::command]
+pub fn get_records_by_tag(
+    state: State<Database>,
+    tag: String,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<OcrRecord>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let like_pattern = format!("%{}%", tag);
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM ocr_record WHERE tags LIKE ?1 ORDER BY timestamp DESC",
+            OCR_RECORD_COLUMNS
+        ))
+        .map_err(|e| e.to_string())?;
+    let candidates: Vec<OcrRecord> = stmt
+        .query_map(params![like_pattern], row_to_record)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let matching: Vec<OcrRecord> = candidates
+        .into_iter()
+        .filter(|r| r.tags.as_deref().is_some_and(|tags| split_tags(tags).any(|t| t == tag)))
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect();
+    Ok(matching)
+}
+
+fn csv_quote(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Deletes OCR records (and their image files) older than the `history_retention_days`
+/// setting allows, run once at startup so the DB and history folder don't grow without
+/// bound on machines that capture heavily. A retention of `0` (the default) means keep
+/// everything, matching how `history_retention_days` is documented to users.
+pub fn apply_history_retention(app: &AppHandle) -> Result<usize, String> {
+    let retention_days: i64 = get_setting(app.state::<Database>(), "history_retention_days".to_string())?
+        .and_then(|setting| setting.value.parse().ok())
+        .unwrap_or(0);
+    if retention_days <= 0 {
+        return Ok(0);
+    }
+
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+    let cutoff = now_ms - retention_days * 24 * 60 * 60 * 1000;
+    delete_ocr_records_before(app.state::<Database>(), cutoff)
+}
+
+#[tauri::command]
+pub fn delete_ocr_records_before(state: State<Database>, timestamp: i64) -> Result<usize, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare("SELECT id, image_path FROM ocr_record WHERE timestamp < ?1")
+        .map_err(|e| e.to_string())?;
+    let rows: Vec<(i64, Option<String>)> = stmt
+        .query_map(params![timestamp], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    for (id, image_path) in &rows {
+        if let Some(path) = image_path {
+            let _ = std::fs::remove_file(path);
+        }
+        conn.execute("DELETE FROM ocr_record_fts WHERE rowid = ?1", params![id])
+            .map_err(|e| e.to_string())?;
+    }
+
+    let deleted = conn
+        .execute("DELETE FROM ocr_record WHERE timestamp < ?1", params![timestamp])
+        .map_err(|e| e.to_string())?;
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub fn compact_database(state: State<Database>) -> Result<i64, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let size_before = db_byte_size(&conn)?;
+    conn.execute("VACUUM", []).map_err(|e| e.to_string())?;
+    let size_after = db_byte_size(&conn)?;
+    Ok((size_before - size_after).max(0))
+}
+
+fn db_byte_size(conn: &Connection) -> Result<i64, String> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    Ok(page_count * page_size)
+}
+
+#[tauri::command]
+pub fn set_active_model(state: State<Database>, id: i64, model_type: String) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE model_record SET is_active = 0 WHERE model_type = ?1",
+        params![model_type],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE model_record SET is_active = 1 WHERE id = ?1 AND model_type = ?2",
+        params![id, model_type],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_active_model(state: State<Database>, model_type: String) -> Result<Option<ModelRecord>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.query_row(
+        "SELECT id, model_type, name, is_active, created_at FROM model_record WHERE model_type = ?1 AND is_active = 1",
+        params![model_type],
+        row_to_model_record,
+    )
+    .map(Some)
+    .or_else(|e| if e == rusqlite::Error::QueryReturnedNoRows { Ok(None) } else { Err(e.to_string()) })
+}
+
+fn row_to_model_record(row: &rusqlite::Row) -> rusqlite::Result<ModelRecord> {
+    Ok(ModelRecord {
+        id: row.get(0)?,
+        model_type: row.get(1)?,
+        name: row.get(2)?,
+        is_active: row.get::<_, i64>(3)? != 0,
+        created_at: row.get(4)?,
+    })
+}
+
+fn row_to_record(row: &rusqlite::Row) -> rusqlite::Result<OcrRecord> {
+    Ok(OcrRecord {
+        id: row.get(0)?,
+        timestamp: row.get(1)?,
+        image_data: row.get(2)?,
+        image_path: row.get(3)?,
+        text: row.get(4)?,
+        summary: row.get(5)?,
+        tags: row.get(6)?,
+        ai_answers: row.get(7)?,
+        language: row.get(8)?,
+        confidence: row.get(9)?,
+        created_at: row.get(10)?,
+        updated_at: row.get(11)?,
+        processing_time_ms: row.get(12)?,
+    })
+}
+
+#[tauri::command]
+pub fn create_playlist(state: State<Database>, name: String, created_at: i64) -> Result<i64, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO playlists (name, created_at) VALUES (?1, ?2)",
+        params![name, created_at],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(conn.last_insert_rowid())
+}
+
+#[tauri::command]
+pub fn get_playlists(state: State<Database>) -> Result<Vec<Playlist>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare("SELECT id, name, created_at FROM playlists ORDER BY created_at DESC")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(Playlist {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                created_at: row.get(2)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn add_song_to_playlist(state: State<Database>, playlist_id: i64, song_id: i64) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let next_position: i64 = conn
+        .query_row(
+            "SELECT COALESCE(MAX(position), -1) + 1 FROM playlist_songs WHERE playlist_id = ?1",
+            params![playlist_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR IGNORE INTO playlist_songs (playlist_id, song_id, position) VALUES (?1, ?2, ?3)",
+        params![playlist_id, song_id, next_position],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_playlist_songs(state: State<Database>, playlist_id: i64) -> Result<Vec<Song>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT s.id, s.title, s.artist, s.album, s.file_path, s.added_at, s.play_count, s.last_played_at, s.content_hash
+             FROM songs s JOIN playlist_songs ps ON ps.song_id = s.id
+             WHERE ps.playlist_id = ?1 ORDER BY ps.position ASC",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![playlist_id], row_to_song)
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn remove_song_from_playlist(state: State<Database>, playlist_id: i64, song_id: i64) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM playlist_songs WHERE playlist_id = ?1 AND song_id = ?2",
+        params![playlist_id, song_id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn reorder_playlist(state: State<Database>, playlist_id: i64, song_ids: Vec<i64>) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    for (position, song_id) in song_ids.iter().enumerate() {
+        tx.execute(
+            "UPDATE playlist_songs SET position = ?1 WHERE playlist_id = ?2 AND song_id = ?3",
+            params![position as i64, playlist_id, song_id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_song(state: State<Database>, id: i64) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let file_path: Option<String> = conn
+        .query_row("SELECT file_path FROM songs WHERE id = ?1", params![id], |row| row.get(0))
+        .ok();
+
+    conn.execute("DELETE FROM playlist_songs WHERE song_id = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM songs WHERE id = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+
+    if let Some(path) = file_path {
+        let _ = std::fs::remove_file(path);
+    }
+    Ok(())
+}
+
+fn row_to_song(row: &rusqlite::Row) -> rusqlite::Result<Song> {
+    Ok(Song {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        artist: row.get(2)?,
+        album: row.get(3)?,
+        file_path: row.get(4)?,
+        added_at: row.get(5)?,
+        play_count: row.get(6)?,
+        last_played_at: row.get(7)?,
+        content_hash: row.get(8)?,
+    })
+}
+
+const SONG_COLUMNS: &str =
+    "id, title, artist, album, file_path, added_at, play_count, last_played_at, content_hash";
+
+/// Looks up a song by its content_hash, used by music::process_import to skip files
+/// that were already imported from a different path.
+pub(crate) fn find_song_by_content_hash(
+    state: &State<Database>,
+    content_hash: &str,
+) -> Result<Option<Song>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let sql = format!("SELECT {} FROM songs WHERE content_hash = ?1", SONG_COLUMNS);
+    conn.query_row(&sql, params![content_hash], row_to_song)
+        .map(Some)
+        .or_else(|e| if e == rusqlite::Error::QueryReturnedNoRows { Ok(None) } else { Err(e.to_string()) })
+}
+
+/// Inserts a new song row, used by music::process_import. Kept here (rather than
+/// duplicating the SQL in the music module) since every other songs-table write goes
+/// through database::mod.
+pub(crate) fn insert_song(
+    state: &State<Database>,
+    title: &str,
+    file_path: &str,
+    added_at: i64,
+    content_hash: &str,
+) -> Result<Song, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO songs (title, artist, album, file_path, added_at, play_count, last_played_at, content_hash)
+         VALUES (?1, NULL, NULL, ?2, ?3, 0, NULL, ?4)",
+        params![title, file_path, added_at, content_hash],
+    )
+    .map_err(|e| e.to_string())?;
+    let id = conn.last_insert_rowid();
+    Ok(Song {
+        id: Some(id),
+        title: title.to_string(),
+        artist: None,
+        album: None,
+        file_path: file_path.to_string(),
+        added_at,
+        play_count: 0,
+        last_played_at: None,
+        content_hash: Some(content_hash.to_string()),
+    })
+}
+
+#[tauri::command]
+pub fn get_all_songs(state: State<Database>) -> Result<Vec<Song>, String> {
+    search_songs(state, None, 1_000_000, 0, None)
+}
+
+#[tauri::command]
+pub fn search_songs(
+    state: State<Database>,
+    query: Option<String>,
+    limit: i64,
+    offset: i64,
+    sort: Option<String>,
+) -> Result<Vec<Song>, String> {
+    let order_by = match sort.as_deref() {
+        Some("artist") => "artist ASC",
+        Some("added_at") => "added_at DESC",
+        _ => "title ASC",
+    };
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+
+    let rows: Vec<Song> = if let Some(q) = query.filter(|q| !q.trim().is_empty()) {
+        let like = format!("%{}%", q);
+        let sql = format!(
+            "SELECT {} FROM songs WHERE title LIKE ?1 OR artist LIKE ?1 OR album LIKE ?1 ORDER BY {} LIMIT ?2 OFFSET ?3",
+            SONG_COLUMNS, order_by
+        );
+        let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+        stmt.query_map(params![like, limit, offset], row_to_song)
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?
+    } else {
+        let sql = format!("SELECT {} FROM songs ORDER BY {} LIMIT ?1 OFFSET ?2", SONG_COLUMNS, order_by);
+        let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+        stmt.query_map(params![limit, offset], row_to_song)
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?
+    };
+
+    Ok(rows)
+}
+
+#[tauri::command]
+pub fn mark_song_played(state: State<Database>, id: i64, played_at: i64) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "UPDATE songs SET play_count = play_count + 1, last_played_at = ?1 WHERE id = ?2",
+        params![played_at, id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_recently_played(state: State<Database>, limit: i64) -> Result<Vec<Song>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let sql = format!(
+        "SELECT {} FROM songs WHERE last_played_at IS NOT NULL ORDER BY last_played_at DESC LIMIT ?1",
+        SONG_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let rows = stmt.query_map(params![limit], row_to_song).map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_most_played(state: State<Database>, limit: i64) -> Result<Vec<Song>, String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    let sql = format!("SELECT {} FROM songs ORDER BY play_count DESC LIMIT ?1", SONG_COLUMNS);
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let rows = stmt.query_map(params![limit], row_to_song).map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_playlist(state: State<Database>, id: i64) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM playlist_songs WHERE playlist_id = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM playlists WHERE id = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn rename_playlist(state: State<Database>, id: i64, name: String) -> Result<(), String> {
+    let conn = state.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute("UPDATE playlists SET name = ?1 WHERE id = ?2", params![name, id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::process::{Child, ExitStatus};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn registry() -> &'static Mutex<HashMap<String, Child>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, Child>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn new_job_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("media-{}", nanos)
+}
+
+pub fn register(job_id: String, child: Child) {
+    registry().lock().unwrap().insert(job_id, child);
+}
+
+/// Polls the job's process without removing it from the registry, so a concurrent
+/// cancel() call can still find and kill it. Returns an error if the job isn't
+/// registered anymore, which happens once it's been cancelled.
+pub fn try_wait(job_id: &str) -> Result<Option<ExitStatus>, String> {
+    let mut guard = registry().lock().unwrap();
+    match guard.get_mut(job_id) {
+        Some(child) => child.try_wait().map_err(|e| e.to_string()),
+        None => Err(format!("media job {} is no longer registered", job_id)),
+    }
+}
+
+pub fn unregister(job_id: &str) {
+    registry().lock().unwrap().remove(job_id);
+}
+
+/// Kills the job's helper child process if it's still running. Returns true if a
+/// matching job was found (and removed), false if it already finished or never existed.
+pub fn cancel(job_id: &str) -> bool {
+    match registry().lock().unwrap().remove(job_id) {
+        Some(mut child) => {
+            let _ = child.kill();
+            true
+        }
+        None => false,
+    }
+}
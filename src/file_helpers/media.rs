@@ -0,0 +1,422 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{AppHandle, Window};
+
+use super::job_registry;
+use super::python_bridge::{run_python_command, run_python_command_streaming};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaInfo {
+    pub duration_seconds: f64,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub codec: Option<String>,
+    pub bitrate: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertOptions {
+    pub input: String,
+    pub output: String,
+    pub format: Option<String>,
+    pub video_bitrate: Option<String>,
+    pub audio_bitrate: Option<String>,
+    /// "nvenc" | "qsv" | "vaapi" | "cpu" (default). Falls back to cpu with a warning in
+    /// the result's message if the requested encoder isn't available in this ffmpeg build.
+    pub encoder: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressOptions {
+    pub input: String,
+    pub output: String,
+    pub crf: Option<u32>,
+    pub encoder: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MuxOptions {
+    pub inputs: Vec<String>,
+    pub output: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversionResult {
+    pub success: bool,
+    pub output_path: String,
+    pub output_size: u64,
+    /// Id of the job that produced this result, for streaming operations (convert,
+    /// compress, batch). None for operations that never go through the job registry
+    /// (e.g. mux_streams), since there's nothing to cancel.
+    pub job_id: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FfmpegStatus {
+    pub available: bool,
+    pub version: Option<String>,
+    pub path: Option<String>,
+    pub has_libx265: bool,
+    pub has_nvenc: bool,
+}
+
+/// Probes `ffmpeg -version`/`-encoders` directly (no Python involved) so the Settings
+/// screen can warn users proactively on startup instead of every media command failing
+/// cryptically the first time it tries to shell out to the Python helper.
+#[tauri::command]
+pub fn check_ffmpeg() -> Result<FfmpegStatus, String> {
+    let which = std::process::Command::new("which")
+        .arg("ffmpeg")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|path| !path.is_empty());
+
+    let version_output = std::process::Command::new("ffmpeg").arg("-version").output();
+    let version = match &version_output {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .map(|line| line.to_string()),
+        _ => None,
+    };
+    let available = matches!(&version_output, Ok(output) if output.status.success());
+
+    let encoders = std::process::Command::new("ffmpeg")
+        .arg("-hide_banner")
+        .arg("-encoders")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+        .unwrap_or_default();
+
+    Ok(FfmpegStatus {
+        available,
+        version,
+        path: which,
+        has_libx265: encoders.contains("libx265"),
+        has_nvenc: encoders.contains("_nvenc"),
+    })
+}
+
+fn codec_for_encoder(encoder: &str) -> &'static str {
+    match encoder {
+        "nvenc" => "hevc_nvenc",
+        "qsv" => "hevc_qsv",
+        "vaapi" => "hevc_vaapi",
+        _ => "libx265",
+    }
+}
+
+/// Shells out to `ffmpeg -encoders` and checks whether `codec` is listed, so we can fall
+/// back to software encoding instead of failing outright when hardware acceleration
+/// isn't available on this machine.
+fn encoder_available(codec: &str) -> bool {
+    std::process::Command::new("ffmpeg")
+        .arg("-hide_banner")
+        .arg("-encoders")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(codec))
+        .unwrap_or(false)
+}
+
+/// Resolves the requested encoder ("nvenc"/"qsv"/"vaapi"/"cpu"/unset) to an ffmpeg codec
+/// name, falling back to the CPU encoder (with a warning message) if the requested one
+/// isn't available in this ffmpeg build.
+fn resolve_encoder(requested: Option<&str>) -> (String, Option<String>) {
+    let requested = requested.unwrap_or("cpu");
+    let codec = codec_for_encoder(requested);
+
+    if requested == "cpu" || encoder_available(codec) {
+        return (codec.to_string(), None);
+    }
+
+    (
+        codec_for_encoder("cpu").to_string(),
+        Some(format!(
+            "{} encoder ({}) not available on this machine, falling back to CPU (libx265)",
+            requested, codec
+        )),
+    )
+}
+
+#[tauri::command]
+pub fn get_media_info(app: AppHandle, path: String) -> Result<MediaInfo, String> {
+    let value = run_python_command(&app, &json!({ "action": "info", "input": path }))?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Converts a media file, streaming ffmpeg's progress from the Python helper's stderr
+/// as a media-progress event (computed against get_media_info's duration) instead of
+/// leaving the UI with no feedback until a multi-GB transcode finishes.
+#[tauri::command]
+pub fn convert_media_file(
+    app: AppHandle,
+    window: Window,
+    options: ConvertOptions,
+) -> Result<ConversionResult, String> {
+    let duration_seconds = get_media_info(app.clone(), options.input.clone())
+        .map(|info| info.duration_seconds)
+        .unwrap_or(0.0);
+    let (codec, warning) = resolve_encoder(options.encoder.as_deref());
+    let job_id = job_registry::new_job_id();
+    let value = run_python_command_streaming(
+        &app,
+        &window,
+        &job_id,
+        &json!({ "action": "convert", "options": options, "codec": codec }),
+        duration_seconds,
+    )?;
+    let mut result: ConversionResult = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    result.job_id = Some(job_id);
+    result.message = warning.or(result.message);
+    Ok(result)
+}
+
+/// Same streaming treatment as convert_media_file — compression runs are often the
+/// longest-running media job, so they need progress even more.
+#[tauri::command]
+pub fn compress_video(
+    app: AppHandle,
+    window: Window,
+    options: CompressOptions,
+) -> Result<ConversionResult, String> {
+    let duration_seconds = get_media_info(app.clone(), options.input.clone())
+        .map(|info| info.duration_seconds)
+        .unwrap_or(0.0);
+    let (codec, warning) = resolve_encoder(options.encoder.as_deref());
+    let job_id = job_registry::new_job_id();
+    let value = run_python_command_streaming(
+        &app,
+        &window,
+        &job_id,
+        &json!({ "action": "compress", "options": options, "codec": codec }),
+        duration_seconds,
+    )?;
+    let mut result: ConversionResult = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    result.job_id = Some(job_id);
+    result.message = warning.or(result.message);
+    Ok(result)
+}
+
+/// Kills the media_helper.py child process backing a convert/compress/batch job, identified by
+/// the job_id returned (via the media-job-started event) when the job began. Emits
+/// media-cancelled so the UI can reset without waiting for the job's promise to settle.
+#[tauri::command]
+pub fn cancel_media_job(window: Window, job_id: String) -> Result<(), String> {
+    if job_registry::cancel(&job_id) {
+        let _ = window.emit("media-cancelled", &job_id);
+        Ok(())
+    } else {
+        Err(format!("no running media job with id {}", job_id))
+    }
+}
+
+#[tauri::command]
+pub fn batch_convert(app: AppHandle, window: Window, jobs: Vec<ConvertOptions>) -> Vec<ConversionResult> {
+    jobs.into_iter()
+        .map(|options| {
+            let output_path = options.output.clone();
+            convert_media_file(app.clone(), window.clone(), options).unwrap_or_else(|e| ConversionResult {
+                success: false,
+                output_path,
+                output_size: 0,
+                job_id: None,
+                message: Some(e),
+            })
+        })
+        .collect()
+}
+
+/// Escapes a subtitle path for ffmpeg's `subtitles=` filter. The filter's own argument
+/// parser treats `:` and `\` as special, which breaks Windows paths like `C:\clips\a.srt`
+/// unless the drive-letter colon and backslashes are escaped.
+fn escape_subtitle_path(path: &str) -> String {
+    path.replace('\\', "\\\\").replace(':', "\\:")
+}
+
+/// Hardcodes a subtitle track (.srt or .ass) into the video so it survives platforms
+/// that strip or ignore soft subtitle streams. `style` is passed through as
+/// `force_style` (ASS style overrides, e.g. "FontSize=24,PrimaryColour=&Hffffff&").
+#[tauri::command]
+pub fn burn_subtitles(
+    app: AppHandle,
+    input: String,
+    subtitle: String,
+    output: String,
+    style: Option<String>,
+) -> Result<ConversionResult, String> {
+    let mut filter = format!("subtitles='{}'", escape_subtitle_path(&subtitle));
+    if let Some(style) = &style {
+        filter.push_str(&format!(":force_style='{}'", style));
+    }
+
+    let value = run_python_command(
+        &app,
+        &json!({
+            "action": "burn_subtitles",
+            "input": input,
+            "output": output,
+            "filter": filter,
+        }),
+    )?;
+    let mut result: ConversionResult = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    result.job_id = None;
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoudnessResult {
+    pub success: bool,
+    pub output_path: String,
+    pub output_size: u64,
+    pub input_lufs: f64,
+    pub output_lufs: f64,
+}
+
+/// Two-pass loudnorm: the first pass measures the input's integrated loudness (parsing
+/// the JSON stats ffmpeg prints at the end of the filter's stderr output), and the
+/// second pass applies the filter with those measured values so the result actually
+/// hits the target instead of loudnorm's single-pass dynamic estimate.
+#[tauri::command]
+pub fn normalize_audio(
+    app: AppHandle,
+    input: String,
+    output: String,
+    target_lufs: Option<f64>,
+) -> Result<LoudnessResult, String> {
+    let value = run_python_command(
+        &app,
+        &json!({
+            "action": "normalize_audio",
+            "input": input,
+            "output": output,
+            "target_lufs": target_lufs.unwrap_or(-16.0),
+        }),
+    )?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn mux_streams(app: AppHandle, options: MuxOptions) -> Result<ConversionResult, String> {
+    let value = run_python_command(&app, &json!({ "action": "mux", "options": options }))?;
+    let mut result: ConversionResult = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    result.job_id = None;
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameResult {
+    pub output_path: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+/// Seeks to `timestamp` (ffmpeg-style, e.g. "00:01:23.500") and writes a single frame
+/// as a poster image. Rejects timestamps past the end of the clip up front instead of
+/// letting ffmpeg silently produce an empty/black frame.
+#[tauri::command]
+pub fn extract_frame(
+    app: AppHandle,
+    input: String,
+    timestamp: String,
+    output: String,
+) -> Result<FrameResult, String> {
+    let seconds = parse_timestamp(&timestamp)?;
+    let info = get_media_info(app.clone(), input.clone())?;
+    if seconds > info.duration_seconds {
+        return Err(format!(
+            "timestamp {} ({}s) is past the end of the media ({}s)",
+            timestamp, seconds, info.duration_seconds
+        ));
+    }
+
+    let value = run_python_command(
+        &app,
+        &json!({
+            "action": "extract_frame",
+            "input": input,
+            "timestamp": timestamp,
+            "output": output,
+        }),
+    )?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Writes one frame every `every_seconds` for a sprite sheet / scrubber preview, named
+/// `frame_0001.png`, `frame_0002.png`, ... inside `output_dir`.
+#[tauri::command]
+pub fn extract_frames(
+    app: AppHandle,
+    input: String,
+    every_seconds: f64,
+    output_dir: String,
+) -> Result<Vec<String>, String> {
+    if every_seconds <= 0.0 {
+        return Err("every_seconds must be greater than zero".to_string());
+    }
+
+    let value = run_python_command(
+        &app,
+        &json!({
+            "action": "extract_frames",
+            "input": input,
+            "every_seconds": every_seconds,
+            "output_dir": output_dir,
+        }),
+    )?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Parses an ffmpeg-style `HH:MM:SS(.ms)` or plain-seconds timestamp into seconds.
+fn parse_timestamp(timestamp: &str) -> Result<f64, String> {
+    let parts: Vec<&str> = timestamp.split(':').collect();
+    let invalid = || format!("invalid timestamp: {}", timestamp);
+
+    match parts.as_slice() {
+        [seconds] => seconds.parse::<f64>().map_err(|_| invalid()),
+        [minutes, seconds] => {
+            let m: f64 = minutes.parse().map_err(|_| invalid())?;
+            let s: f64 = seconds.parse().map_err(|_| invalid())?;
+            Ok(m * 60.0 + s)
+        }
+        [hours, minutes, seconds] => {
+            let h: f64 = hours.parse().map_err(|_| invalid())?;
+            let m: f64 = minutes.parse().map_err(|_| invalid())?;
+            let s: f64 = seconds.parse().map_err(|_| invalid())?;
+            Ok(h * 3600.0 + m * 60.0 + s)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Clips input[start, start+duration] to a GIF via ffmpeg's two-stage
+/// palettegen/paletteuse pipeline, which produces far better quality than a single-pass
+/// encode. fps/width default to 12/480, which keep typical clips under a few MB.
+#[tauri::command]
+pub fn create_gif(
+    app: AppHandle,
+    input: String,
+    start: String,
+    duration: String,
+    fps: Option<i32>,
+    width: Option<i32>,
+    output: String,
+) -> Result<ConversionResult, String> {
+    let value = run_python_command(
+        &app,
+        &json!({
+            "action": "gif",
+            "input": input,
+            "start": start,
+            "duration": duration,
+            "fps": fps.unwrap_or(12),
+            "width": width.unwrap_or(480),
+            "output": output,
+        }),
+    )?;
+    let mut result: ConversionResult = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    result.job_id = None;
+    Ok(result)
+}
@@ -0,0 +1,3 @@
+pub mod job_registry;
+pub mod media;
+pub mod python_bridge;
@@ -0,0 +1,142 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, Window};
+
+use super::job_registry;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(150);
+
+fn helper_script_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .resolve_resource("resources/media_helper.py")
+        .ok_or_else(|| "media_helper.py resource not found".to_string())
+}
+
+/// Runs the bundled media_helper.py with the given JSON args and parses its stdout as
+/// JSON. Blocks until the process exits, which is fine for quick operations like
+/// get_media_info but gives no feedback for a multi-GB transcode — those should use
+/// run_python_command_streaming instead.
+pub fn run_python_command(app: &AppHandle, args: &Value) -> Result<Value, String> {
+    let script = helper_script_path(app)?;
+    let output = Command::new("python3")
+        .arg(&script)
+        .arg(args.to_string())
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct MediaProgressPayload {
+    percent: f64,
+}
+
+/// Like run_python_command, but spawns the helper under `job_id` in the job registry
+/// (so cancel_media_job can find and kill it while it's running) instead of blocking on
+/// `.output()`. Reads stderr line-by-line for ffmpeg's `out_time_ms=`/`progress=` output,
+/// emitting a media-progress event with percent computed against `duration_seconds`.
+/// Emits media-job-started with the job id as soon as the process is registered, so the
+/// caller can cancel it before this function returns. The final parsed JSON on stdout is
+/// still returned once the process exits.
+pub fn run_python_command_streaming(
+    app: &AppHandle,
+    window: &Window,
+    job_id: &str,
+    args: &Value,
+    duration_seconds: f64,
+) -> Result<Value, String> {
+    let script = helper_script_path(app)?;
+    let mut child = Command::new("python3")
+        .arg(&script)
+        .arg(args.to_string())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let stdout_buf = Arc::new(Mutex::new(Vec::new()));
+    let stdout_handle = child.stdout.take().map(|mut stdout| {
+        let stdout_buf = Arc::clone(&stdout_buf);
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            if stdout.read_to_end(&mut buf).is_ok() {
+                *stdout_buf.lock().unwrap() = buf;
+            }
+        })
+    });
+
+    let stderr_buf = Arc::new(Mutex::new(String::new()));
+    let stderr_handle = child.stderr.take().map(|stderr| {
+        let window = window.clone();
+        let stderr_buf = Arc::clone(&stderr_buf);
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().flatten() {
+                {
+                    let mut captured = stderr_buf.lock().unwrap();
+                    captured.push_str(&line);
+                    captured.push('\n');
+                }
+
+                let Some(raw_ms) = line.strip_prefix("out_time_ms=") else {
+                    continue;
+                };
+                let Ok(ms) = raw_ms.trim().parse::<f64>() else {
+                    continue;
+                };
+                let percent = if duration_seconds > 0.0 {
+                    ((ms / 1_000_000.0) / duration_seconds * 100.0).clamp(0.0, 100.0)
+                } else {
+                    0.0
+                };
+                let _ = window.emit("media-progress", MediaProgressPayload { percent });
+            }
+        })
+    });
+
+    job_registry::register(job_id.to_string(), child);
+    let _ = window.emit("media-job-started", job_id);
+
+    let status = loop {
+        match job_registry::try_wait(job_id) {
+            Ok(Some(status)) => break status,
+            Ok(None) => {
+                std::thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            Err(_) => return Err(format!("media job {} was cancelled", job_id)),
+        }
+    };
+    job_registry::unregister(job_id);
+
+    // Wait for the reader threads to finish draining the pipes before reading the
+    // buffers they fill — the child exiting doesn't mean the threads have caught up,
+    // and reading early can race a partially-filled buffer into a spurious failure.
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    if let Some(handle) = stderr_handle {
+        let _ = handle.join();
+    }
+
+    if !status.success() {
+        let captured = stderr_buf.lock().unwrap().clone();
+        return Err(if captured.is_empty() {
+            format!("media helper exited with {}", status)
+        } else {
+            captured
+        });
+    }
+
+    let stdout_bytes = stdout_buf.lock().unwrap().clone();
+    serde_json::from_slice(&stdout_bytes).map_err(|e| e.to_string())
+}
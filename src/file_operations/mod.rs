@@ -0,0 +1,141 @@
+use serde::Serialize;
+use std::path::Path;
+
+/// EXDEV: "Invalid cross-device link", returned by rename(2) when src and dest are on
+/// different filesystems/volumes. `fs::rename` can't do that atomically, so we fall
+/// back to copy+delete.
+#[cfg(unix)]
+const CROSS_DEVICE_ERRNO: i32 = 18;
+#[cfg(windows)]
+const CROSS_DEVICE_ERRNO: i32 = 17;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileOperationResult {
+    pub success: bool,
+    pub message: String,
+}
+
+fn ok(message: impl Into<String>) -> FileOperationResult {
+    FileOperationResult {
+        success: true,
+        message: message.into(),
+    }
+}
+
+fn err(message: impl Into<String>) -> FileOperationResult {
+    FileOperationResult {
+        success: false,
+        message: message.into(),
+    }
+}
+
+#[tauri::command]
+pub fn rename_file(src: String, dest: String) -> FileOperationResult {
+    match std::fs::rename(&src, &dest) {
+        Ok(()) => ok(format!("renamed {} to {}", src, dest)),
+        Err(e) => err(e.to_string()),
+    }
+}
+
+/// Moves a file, falling back to copy+delete when `fs::rename` fails with a
+/// cross-device error (e.g. moving across two mounted volumes), since `rename` can't
+/// do that atomically.
+#[tauri::command]
+pub fn move_file(src: String, dest: String, overwrite: Option<bool>) -> FileOperationResult {
+    if !overwrite.unwrap_or(false) && Path::new(&dest).exists() {
+        return err(format!("{} already exists", dest));
+    }
+
+    match std::fs::rename(&src, &dest) {
+        Ok(()) => ok(format!("moved {} to {}", src, dest)),
+        Err(e) if e.raw_os_error() == Some(CROSS_DEVICE_ERRNO) => match std::fs::copy(&src, &dest) {
+            Ok(_) => match std::fs::remove_file(&src) {
+                Ok(()) => ok(format!("moved {} to {}", src, dest)),
+                Err(e) => err(format!("copied but failed to remove source: {}", e)),
+            },
+            Err(e) => err(e.to_string()),
+        },
+        Err(e) => err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+pub fn copy_file(src: String, dest: String, overwrite: Option<bool>) -> FileOperationResult {
+    if !overwrite.unwrap_or(false) && Path::new(&dest).exists() {
+        return err(format!("{} already exists", dest));
+    }
+
+    match std::fs::copy(&src, &dest) {
+        Ok(_) => ok(format!("copied {} to {}", src, dest)),
+        Err(e) => err(e.to_string()),
+    }
+}
+
+/// Moves a file to the OS recycle bin/trash instead of unlinking it, so an accidental
+/// delete from the file manager is recoverable.
+#[tauri::command]
+pub fn delete_to_trash(path: String) -> FileOperationResult {
+    match trash::delete(&path) {
+        Ok(()) => ok(format!("moved {} to trash", path)),
+        Err(e) => err(e.to_string()),
+    }
+}
+
+/// Hard-deletes a file with no recovery path. Kept separate from delete_to_trash so
+/// the UI can require explicit confirmation before calling it.
+#[tauri::command]
+pub fn delete_permanently(path: String) -> FileOperationResult {
+    match std::fs::remove_file(&path) {
+        Ok(()) => ok(format!("permanently deleted {}", path)),
+        Err(e) => err(e.to_string()),
+    }
+}
+
+/// Expands `{n}` (the counter, starting at `start`), `{name}` (stem), and `{ext}`
+/// tokens in `pattern` for one file.
+fn expand_pattern(pattern: &str, counter: u64, stem: &str, ext: &str) -> String {
+    pattern
+        .replace("{n}", &counter.to_string())
+        .replace("{name}", stem)
+        .replace("{ext}", ext)
+}
+
+/// Renames many files at once using a `{n}`/`{name}`/`{ext}` pattern, e.g.
+/// "vacation_{n}.{ext}" turning IMG_0001.jpg, IMG_0002.jpg, ... into vacation_1.jpg,
+/// vacation_2.jpg, etc. Renamed sequentially starting at `start`; a collision with an
+/// existing file produces an error entry for that file rather than aborting the batch.
+#[tauri::command]
+pub fn batch_rename(paths: Vec<String>, pattern: String, start: u64) -> Vec<FileOperationResult> {
+    let mut counter = start;
+    paths
+        .into_iter()
+        .map(|path| {
+            let path_buf = Path::new(&path);
+            let stem = path_buf
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let ext = path_buf
+                .extension()
+                .map(|e| e.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            let new_name = expand_pattern(&pattern, counter, &stem, &ext);
+            counter += 1;
+
+            let dest = path_buf
+                .parent()
+                .map(|parent| parent.join(&new_name))
+                .unwrap_or_else(|| Path::new(&new_name).to_path_buf());
+
+            if dest.exists() {
+                return err(format!("{} already exists", dest.display()));
+            }
+
+            match std::fs::rename(&path, &dest) {
+                Ok(()) => ok(format!("renamed {} to {}", path, dest.display())),
+                Err(e) => err(e.to_string()),
+            }
+        })
+        .collect()
+}
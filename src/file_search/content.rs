@@ -0,0 +1,103 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Serialize;
+
+use super::MAX_CONTENT_SCAN_BYTES;
+
+/// Extension → MIME type mapping, shared by get_file_metadata and read_file_base64 so
+/// both report the same type for the same file.
+fn mime_type_for_extension(ext: &str) -> &'static str {
+    match ext.to_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "txt" => "text/plain",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "html" | "htm" => "text/html",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileMetadata {
+    pub path: String,
+    pub name: String,
+    pub size: u64,
+    pub mime_type: String,
+}
+
+#[tauri::command]
+pub fn get_file_metadata(path: String) -> Result<FileMetadata, String> {
+    let metadata = std::fs::metadata(&path).map_err(|e| e.to_string())?;
+    let name = std::path::Path::new(&path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.clone());
+    let mime_type = std::path::Path::new(&path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(mime_type_for_extension)
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    Ok(FileMetadata {
+        path,
+        name,
+        size: metadata.len(),
+        mime_type,
+    })
+}
+
+/// Reads a text file, refusing anything over MAX_CONTENT_SCAN_BYTES so a huge or
+/// binary file can't stall the frontend or blow up memory.
+#[tauri::command]
+pub fn read_file_content(path: String) -> Result<String, String> {
+    let metadata = std::fs::metadata(&path).map_err(|e| e.to_string())?;
+    if metadata.len() > MAX_CONTENT_SCAN_BYTES {
+        return Err(format!(
+            "file is {} bytes, over the {} byte limit",
+            metadata.len(),
+            MAX_CONTENT_SCAN_BYTES
+        ));
+    }
+    std::fs::read_to_string(&path).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileBase64 {
+    pub data: String,
+    pub mime_type: String,
+}
+
+/// Companion to read_file_content for binary files (e.g. image thumbnails): reads up
+/// to max_bytes and returns it as base64 plus the detected MIME type, instead of
+/// erroring out on non-UTF-8 content.
+#[tauri::command]
+pub fn read_file_base64(path: String, max_bytes: u64) -> Result<FileBase64, String> {
+    let metadata = std::fs::metadata(&path).map_err(|e| e.to_string())?;
+    if metadata.len() > max_bytes {
+        return Err(format!(
+            "file is {} bytes, over the {} byte limit",
+            metadata.len(),
+            max_bytes
+        ));
+    }
+    let bytes = std::fs::read(&path).map_err(|e| e.to_string())?;
+    let mime_type = std::path::Path::new(&path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(mime_type_for_extension)
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    Ok(FileBase64 {
+        data: STANDARD.encode(&bytes),
+        mime_type,
+    })
+}
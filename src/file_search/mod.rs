@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub mod content;
+
+#[cfg(target_os = "windows")]
+mod search_windows;
+
+#[cfg(not(target_os = "windows"))]
+mod search_unix;
+
+/// Files larger than this are skipped for content scanning (and rejected by
+/// `read_file_content`) so a search over a folder with a stray multi-GB file doesn't
+/// hang or blow up memory.
+pub const MAX_CONTENT_SCAN_BYTES: u64 = 5 * 1024 * 1024;
+
+const CONTEXT_LINES: usize = 1;
+
+/// Extensions that are almost certainly binary, so content search skips them instead
+/// of wasting time reading (and likely failing to UTF-8-decode) them.
+const BINARY_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "ico", "webp", "mp3", "mp4", "wav", "flac", "mov",
+    "avi", "mkv", "zip", "rar", "7z", "gz", "tar", "exe", "dll", "so", "dylib", "pdf", "db",
+    "sqlite", "bin",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub path: String,
+    pub name: String,
+    pub size: u64,
+    pub modified: Option<String>,
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileSearchOptions {
+    pub query: String,
+    pub root: String,
+    #[serde(default)]
+    pub max_results: Option<usize>,
+    /// Extensions to restrict results to (e.g. ["pdf", "txt"]), case-insensitive.
+    #[serde(default)]
+    pub file_types: Option<Vec<String>>,
+    /// When true, also grep file contents and populate `snippet` on matches.
+    #[serde(default)]
+    pub content: bool,
+    /// Number of results to skip before returning, applied after sorting. Paired with
+    /// max_results to page through a large result set.
+    #[serde(default)]
+    pub offset: Option<usize>,
+    /// "name", "modified", or "size". Sorting happens here in Rust, post-collection,
+    /// not in the underlying PowerShell/index query or directory walk.
+    #[serde(default)]
+    pub sort: Option<String>,
+}
+
+/// Reads a text file for content search, refusing anything over the 5MB guard so a
+/// huge file can't stall the search.
+fn read_text_for_search(path: &Path) -> Option<String> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if metadata.len() > MAX_CONTENT_SCAN_BYTES {
+        return None;
+    }
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        if BINARY_EXTENSIONS.contains(&ext.to_lowercase().as_str()) {
+            return None;
+        }
+    }
+    std::fs::read_to_string(path).ok()
+}
+
+/// Scans a file's contents for `query`, returning the first matching line with a line
+/// of surrounding context on either side, joined with newlines.
+fn find_content_snippet(path: &Path, query: &str) -> Option<String> {
+    let text = read_text_for_search(path)?;
+    let needle = query.to_lowercase();
+    let lines: Vec<&str> = text.lines().collect();
+
+    let match_idx = lines
+        .iter()
+        .position(|line| line.to_lowercase().contains(&needle))?;
+
+    let start = match_idx.saturating_sub(CONTEXT_LINES);
+    let end = (match_idx + CONTEXT_LINES + 1).min(lines.len());
+    Some(lines[start..end].join("\n"))
+}
+
+fn populate_content_snippets(results: &mut [SearchResult], query: &str) {
+    for result in results.iter_mut() {
+        result.snippet = find_content_snippet(Path::new(&result.path), query);
+    }
+}
+
+/// Keeps only results whose extension matches `file_types` (case-insensitively).
+/// An empty or absent list is a no-op, since that means "no filter".
+fn apply_file_type_filter(results: Vec<SearchResult>, file_types: &Option<Vec<String>>) -> Vec<SearchResult> {
+    let Some(file_types) = file_types else {
+        return results;
+    };
+    if file_types.is_empty() {
+        return results;
+    }
+    let wanted: Vec<String> = file_types.iter().map(|ext| ext.to_lowercase()).collect();
+
+    results
+        .into_iter()
+        .filter(|result| {
+            Path::new(&result.name)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| wanted.contains(&ext.to_lowercase()))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+fn sort_results(results: &mut Vec<SearchResult>, sort: &Option<String>) {
+    match sort.as_deref() {
+        Some("name") => results.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+        Some("modified") => results.sort_by(|a, b| b.modified.cmp(&a.modified)),
+        Some("size") => results.sort_by(|a, b| b.size.cmp(&a.size)),
+        _ => {}
+    }
+}
+
+fn paginate_results(results: Vec<SearchResult>, offset: Option<usize>) -> Vec<SearchResult> {
+    match offset {
+        Some(offset) => results.into_iter().skip(offset).collect(),
+        None => results,
+    }
+}
+
+/// Searches `options.root` for files matching `options.query`, optionally grepping
+/// contents when `options.content` is set. Results are sorted (by `sort`) and paged
+/// (by `offset`/`max_results`) after collection, not by the underlying platform search.
+#[tauri::command]
+pub fn search_files(options: FileSearchOptions) -> Result<Vec<SearchResult>, String> {
+    // The platform search collects every name match; max_results is applied below.
+    let unbounded = FileSearchOptions { max_results: None, ..options.clone() };
+    #[cfg(target_os = "windows")]
+    let results = search_windows::search(&unbounded)?;
+    #[cfg(not(target_os = "windows"))]
+    let results = search_unix::search(&unbounded)?;
+
+    let mut results = apply_file_type_filter(results, &options.file_types);
+
+    if options.content {
+        populate_content_snippets(&mut results, &options.query);
+    }
+
+    sort_results(&mut results, &options.sort);
+    let mut results = paginate_results(results, options.offset);
+
+    if let Some(max) = options.max_results {
+        results.truncate(max);
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str) -> SearchResult {
+        SearchResult {
+            path: format!("/tmp/{}", name),
+            name: name.to_string(),
+            size: 0,
+            modified: None,
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn file_types_filter_excludes_non_matching_extensions() {
+        let results = vec![result("report.pdf"), result("notes.txt"), result("photo.png")];
+        let filtered = apply_file_type_filter(results, &Some(vec!["pdf".to_string()]));
+        let names: Vec<&str> = filtered.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["report.pdf"]);
+    }
+
+    #[test]
+    fn file_types_filter_is_noop_when_unset() {
+        let results = vec![result("report.pdf"), result("notes.txt")];
+        let filtered = apply_file_type_filter(results.clone(), &None);
+        assert_eq!(filtered.len(), results.len());
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::UNIX_EPOCH;
+
+use super::{FileSearchOptions, SearchResult};
+
+/// Walks `root` recursively (manually, to stay dependency-free) and filters by name.
+pub fn search(options: &FileSearchOptions) -> Result<Vec<SearchResult>, String> {
+    let query = options.query.to_lowercase();
+    let mut results = Vec::new();
+    walk(std::path::Path::new(&options.root), &query, options.max_results, &mut results);
+    Ok(results)
+}
+
+fn walk(dir: &std::path::Path, query: &str, max_results: Option<usize>, results: &mut Vec<SearchResult>) {
+    if max_results.map_or(false, |max| results.len() >= max) {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        if max_results.map_or(false, |max| results.len() >= max) {
+            return;
+        }
+
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            walk(&path, query, max_results, results);
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.to_lowercase().contains(query) {
+            continue;
+        }
+
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs().to_string());
+
+        results.push(SearchResult {
+            path: path.to_string_lossy().to_string(),
+            name,
+            size: metadata.len(),
+            modified,
+            snippet: None,
+        });
+    }
+}
@@ -0,0 +1,110 @@
+use std::process::Command;
+
+use super::{FileSearchOptions, SearchResult};
+
+fn parse_rows(stdout: &str, query: &str, max_results: Option<usize>) -> Vec<SearchResult> {
+    let query = query.to_lowercase();
+    let mut results = Vec::new();
+    for line in stdout.lines() {
+        let mut parts = line.splitn(3, '|');
+        let (Some(path), Some(size), Some(modified)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let name = path.rsplit(['\\', '/']).next().unwrap_or(path).to_string();
+        if !name.to_lowercase().contains(&query) {
+            continue;
+        }
+
+        results.push(SearchResult {
+            path: path.to_string(),
+            name,
+            size: size.parse().unwrap_or(0),
+            modified: Some(modified.to_string()),
+            snippet: None,
+        });
+
+        if let Some(max) = max_results {
+            if results.len() >= max {
+                break;
+            }
+        }
+    }
+    results
+}
+
+/// Recursively lists files under `root` via PowerShell and filters by name. This is
+/// the fallback used when the Windows Search Index isn't available; it can take
+/// minutes over a large folder.
+fn search_recursive(options: &FileSearchOptions) -> Result<Vec<SearchResult>, String> {
+    let script = format!(
+        "Get-ChildItem -Path '{}' -File -Recurse -ErrorAction SilentlyContinue | \
+         ForEach-Object {{ \"$($_.FullName)|$($_.Length)|$($_.LastWriteTime.ToString('o'))\" }}",
+        options.root.replace('\'', "''")
+    );
+
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(parse_rows(
+        &String::from_utf8_lossy(&output.stdout),
+        &options.query,
+        options.max_results,
+    ))
+}
+
+/// Queries the Windows Search Index (SystemIndex) via OLE DB so typical queries return
+/// in well under a second instead of walking the filesystem. Returns Err if the index
+/// provider isn't registered/available, so the caller can fall back to a manual scan.
+fn search_index(options: &FileSearchOptions) -> Result<Vec<SearchResult>, String> {
+    let scope = format!("file:{}", options.root.replace('\\', "/"));
+    let like = format!("%{}%", options.query);
+
+    // Escape for SQL first (a literal `'` becomes `''` inside the SQL string literal),
+    // then escape the whole SQL text again for the outer PowerShell single-quoted
+    // string it's embedded in. Both `scope` and `like` come straight from the search
+    // UI, so neither layer can be skipped — see search_recursive for the same pattern.
+    let sql = format!(
+        "SELECT System.ItemPathDisplay, System.Size, System.DateModified FROM SystemIndex WHERE System.FileName LIKE '{}' AND SCOPE='{}'",
+        like.replace('\'', "''"),
+        scope.replace('\'', "''")
+    );
+
+    let script = format!(
+        "$conn = New-Object System.Data.OleDb.OleDbConnection('Provider=Search.CollatorDSO;Extended Properties=''Application=Windows'''); \
+         $conn.Open(); \
+         $cmd = $conn.CreateCommand(); \
+         $cmd.CommandText = '{}'; \
+         $reader = $cmd.ExecuteReader(); \
+         while ($reader.Read()) {{ \"$($reader.GetValue(0))|$($reader.GetValue(1))|$($reader.GetValue(2))\" }}; \
+         $conn.Close()",
+        sql.replace('\'', "''")
+    );
+
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(parse_rows(
+        &String::from_utf8_lossy(&output.stdout),
+        &options.query,
+        options.max_results,
+    ))
+}
+
+pub fn search(options: &FileSearchOptions) -> Result<Vec<SearchResult>, String> {
+    match search_index(options) {
+        Ok(results) => Ok(results),
+        Err(_) => search_recursive(options),
+    }
+}
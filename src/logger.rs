@@ -0,0 +1,81 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::Local;
+
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+fn log_path_cell() -> &'static Mutex<Option<PathBuf>> {
+    static LOG_PATH: OnceLock<Mutex<Option<PathBuf>>> = OnceLock::new();
+    LOG_PATH.get_or_init(|| Mutex::new(None))
+}
+
+/// Sets up the log file at `app_data_dir/logs/askocr.log`, rotating the previous file
+/// aside (to `askocr.log.1`, overwriting any older rotation) first if it's grown past
+/// `MAX_LOG_BYTES`. Called once from `main.rs`'s `setup`, before anything else logs.
+/// `println!`/`eprintln!` go nowhere useful in a windowed release build, so this is the
+/// only way bug reports from users end up with any diagnostic trail at all.
+pub fn init(app_data_dir: &Path) -> Result<(), String> {
+    let log_dir = app_data_dir.join("logs");
+    fs::create_dir_all(&log_dir).map_err(|e| e.to_string())?;
+    let path = log_dir.join("askocr.log");
+
+    if let Ok(metadata) = fs::metadata(&path) {
+        if metadata.len() > MAX_LOG_BYTES {
+            let _ = fs::rename(&path, log_dir.join("askocr.log.1"));
+        }
+    }
+
+    *log_path_cell().lock().map_err(|e| e.to_string())? = Some(path);
+    Ok(())
+}
+
+fn log_path() -> Option<PathBuf> {
+    log_path_cell().lock().ok().and_then(|guard| guard.clone())
+}
+
+/// Appends a timestamped line to the log file. Does nothing if `init` hasn't run yet or
+/// the write fails, since logging itself should never be the thing that brings the app
+/// down.
+pub fn log(line: &str) {
+    let Some(path) = log_path() else { return };
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "[{}] {}", timestamp, line);
+    }
+}
+
+/// Returns the last `lines` lines of the log file, oldest first, for an in-app log
+/// viewer so users can copy diagnostics into a bug report without hunting for the file.
+#[tauri::command]
+pub fn get_recent_logs(lines: usize) -> Result<Vec<String>, String> {
+    let Some(path) = log_path() else { return Ok(Vec::new()) };
+    let mut contents = String::new();
+    File::open(&path)
+        .map_err(|e| e.to_string())?
+        .read_to_string(&mut contents)
+        .map_err(|e| e.to_string())?;
+
+    let all_lines: Vec<&str> = contents.lines().collect();
+    let start = all_lines.len().saturating_sub(lines);
+    Ok(all_lines[start..].iter().map(|s| s.to_string()).collect())
+}
+
+/// Opens the OS file manager at the log folder, so attaching the log to a bug report is
+/// a couple of clicks instead of hunting through the app data directory by hand.
+#[tauri::command]
+pub fn open_log_folder() -> Result<(), String> {
+    let path = log_path().ok_or_else(|| "log file not initialized".to_string())?;
+    let folder = path.parent().ok_or_else(|| "log file has no parent directory".to_string())?;
+
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("explorer").arg(folder).spawn();
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(folder).spawn();
+    #[cfg(target_os = "linux")]
+    let result = std::process::Command::new("xdg-open").arg(folder).spawn();
+
+    result.map(|_| ()).map_err(|e| e.to_string())
+}
@@ -0,0 +1,211 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+mod audio_ai;
+mod context;
+mod database;
+mod file_helpers;
+mod file_operations;
+mod file_search;
+mod logger;
+mod music;
+mod ocr;
+mod ollama;
+mod popup;
+mod screenshot;
+mod shortcuts;
+mod tray;
+
+use std::sync::OnceLock;
+
+use tauri::Manager;
+
+use database::Database;
+use music::player::AudioPlayer;
+use popup::PopupState;
+use shortcuts::ShortcutState;
+use tray::TrayState;
+
+/// Set once `setup` has an `AppHandle`, so the panic hook (installed before the app is
+/// built) has something to emit `app-error` on. Panics before `setup` runs still get
+/// logged, just without an event — there's no window listening yet regardless.
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+
+fn main() {
+    std::panic::set_hook(Box::new(|info| {
+        let message = info.to_string();
+        logger::log(&format!("panic: {}", message));
+        if let Some(app) = APP_HANDLE.get() {
+            let _ = app.emit_all("app-error", message);
+        }
+    }));
+
+    tauri::Builder::default()
+        .system_tray(tray::build_system_tray())
+        .on_system_tray_event(|app, event| tray::handle_system_tray_event(app, event))
+        .setup(|app| {
+            let app_dir = app
+                .path_resolver()
+                .app_data_dir()
+                .expect("failed to resolve app data dir");
+            std::fs::create_dir_all(&app_dir).expect("failed to create app data dir");
+            logger::init(&app_dir).expect("failed to initialize log file");
+            let _ = APP_HANDLE.set(app.handle());
+            let db_path = app_dir.join("askocr.db");
+            let db = Database::new(db_path.to_str().unwrap()).expect("failed to open database");
+            app.manage(db);
+            app.manage(TrayState::default());
+            app.manage(ShortcutState::default());
+            app.manage(PopupState::default());
+            app.manage(AudioPlayer::spawn(app.handle()));
+            shortcuts::register_default_shortcuts(&app.handle());
+
+            match database::apply_history_retention(&app.handle()) {
+                Ok(0) => {}
+                Ok(pruned) => logger::log(&format!("startup: pruned {} OCR record(s) past the retention window", pruned)),
+                Err(e) => logger::log(&format!("startup: failed to apply history retention: {}", e)),
+            }
+
+            if let Ok(recent) = tray::get_recent_captures(app.state::<Database>(), tray::MAX_RECENT_IN_MENU as i64) {
+                let _ = tray::tray_update_recent_captures(app.handle(), recent);
+            }
+
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            audio_ai::transcribe_audio,
+            audio_ai::denoise_audio_ffmpeg,
+            audio_ai::download_whisper_model,
+            audio_ai::cancel_model_download,
+            audio_ai::export_speaker_srt,
+            audio_ai::export_transcript,
+            audio_ai::merge_speaker_segments,
+            shortcuts::register_shortcut,
+            shortcuts::unregister_shortcut,
+            shortcuts::update_shortcut,
+            shortcuts::get_registered_shortcuts,
+            shortcuts::set_shortcut_enabled,
+            popup::create_ocr_popup,
+            popup::update_ocr_popup,
+            popup::get_popup_data,
+            popup::keep_popup_open,
+            popup::close_popup,
+            popup::close_all_popups,
+            tray::tray_update_recent_captures,
+            tray::get_recent_captures,
+            database::create_ocr_record,
+            database::get_all_ocr_records,
+            database::update_ocr_record,
+            database::search_ocr_records,
+            database::load_ocr_image,
+            database::set_active_model,
+            database::get_active_model,
+            database::compact_database,
+            database::delete_ocr_records_before,
+            database::export_ocr_records,
+            database::get_ocr_stats,
+            database::get_all_tags,
+            database::get_records_by_tag,
+            logger::get_recent_logs,
+            logger::open_log_folder,
+            database::create_playlist,
+            database::get_playlists,
+            database::add_song_to_playlist,
+            database::delete_playlist,
+            database::rename_playlist,
+            database::get_playlist_songs,
+            database::remove_song_from_playlist,
+            database::reorder_playlist,
+            database::delete_song,
+            database::mark_song_played,
+            database::get_recently_played,
+            database::get_most_played,
+            database::get_all_songs,
+            database::search_songs,
+            database::get_setting,
+            database::set_setting,
+            database::get_setting_typed,
+            database::export_settings,
+            database::import_settings,
+            screenshot::list_monitors,
+            screenshot::capture_fullscreen,
+            screenshot::capture_region,
+            screenshot::show_screenshot_overlay,
+            screenshot::close_screenshot_overlay,
+            screenshot::capture_selected_region,
+            screenshot::capture_fullscreen_delayed,
+            screenshot::capture_region_delayed,
+            screenshot::capture_window,
+            screenshot::pick_color_at,
+            context::get_active_window,
+            context::get_editor_context,
+            context::get_browser_context,
+            context::get_selected_text,
+            context::get_file_explorer_context,
+            ollama::legacy::ollama_generate,
+            ollama::chat::ollama_chat,
+            ollama::chat::ollama_chat_stream,
+            ollama::commands::ollama_list_models,
+            ollama::commands::ollama_list_running,
+            ollama::commands::ollama_pull_model,
+            ollama::commands::cancel_ollama_pull,
+            ollama::commands::ollama_delete_model,
+            ollama::commands::ollama_generate_stream,
+            ollama::commands::warm_up_model,
+            ollama::recommend::recommend_model,
+            ollama::vision::ollama_generate_vision,
+            ollama::detector::check_ollama_installed,
+            ollama::detector::check_ollama_running,
+            ollama::detector::start_ollama_service,
+            ollama::installer::download_ollama,
+            ollama::installer::install_ollama_windows,
+            ollama::installer::install_ollama_one_click,
+            ocr::perform_ocr_native,
+            ocr::perform_ocr_native_and_copy,
+            ocr::perform_ocr_file,
+            ocr::perform_ocr_batch,
+            ocr::list_ocr_languages,
+            ocr::ocr_from_clipboard,
+            ocr::clear_ocr_cache,
+            ocr::reconstruct_table,
+            ocr::scan_codes,
+            file_search::search_files,
+            file_search::content::get_file_metadata,
+            file_search::content::read_file_content,
+            file_search::content::read_file_base64,
+            file_operations::rename_file,
+            file_operations::move_file,
+            file_operations::copy_file,
+            file_operations::delete_to_trash,
+            file_operations::delete_permanently,
+            file_operations::batch_rename,
+            file_helpers::media::get_media_info,
+            file_helpers::media::convert_media_file,
+            file_helpers::media::compress_video,
+            file_helpers::media::cancel_media_job,
+            file_helpers::media::batch_convert,
+            file_helpers::media::mux_streams,
+            file_helpers::media::create_gif,
+            file_helpers::media::extract_frame,
+            file_helpers::media::extract_frames,
+            file_helpers::media::burn_subtitles,
+            file_helpers::media::normalize_audio,
+            file_helpers::media::check_ffmpeg,
+            music::scan_music_folder,
+            music::process_import,
+            music::download::download_spotify,
+            music::player::play_audio,
+            music::player::play_queue,
+            music::player::next_track,
+            music::player::previous_track,
+            music::player::pause_audio,
+            music::player::resume_audio,
+            music::player::stop_audio,
+            music::player::seek_audio,
+            music::player::set_repeat,
+            music::player::set_shuffle,
+            music::player::set_fade_duration,
+            music::player::get_playback_state,
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
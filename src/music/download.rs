@@ -0,0 +1,73 @@
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::{AppHandle, Manager};
+
+const SPOTIFY_HOSTS: &[&str] = &["open.spotify.com", "spotify.com"];
+const YOUTUBE_HOSTS: &[&str] = &["youtube.com", "www.youtube.com", "youtu.be", "music.youtube.com"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadResult {
+    pub success: bool,
+    pub output_path: Option<String>,
+    pub title: Option<String>,
+    pub message: Option<String>,
+}
+
+fn detect_source(url: &Url) -> Option<&'static str> {
+    let host = url.host_str()?;
+    if SPOTIFY_HOSTS.iter().any(|h| host == *h || host.ends_with(&format!(".{}", h))) {
+        Some("spotify")
+    } else if YOUTUBE_HOSTS.iter().any(|h| host == *h || host.ends_with(&format!(".{}", h))) {
+        Some("youtube")
+    } else {
+        None
+    }
+}
+
+fn downloader_script_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .resolve_resource("resources/downloader.py")
+        .ok_or_else(|| "downloader.py resource not found".to_string())
+}
+
+/// Downloads a track from a Spotify or YouTube URL via the bundled downloader.py.
+/// `source` can be passed explicitly ("spotify"/"youtube") or left unset to
+/// auto-detect from the URL's host; an unsupported scheme or host is rejected here,
+/// before ever spawning Python.
+#[tauri::command]
+pub fn download_spotify(
+    app: AppHandle,
+    url: String,
+    source: Option<String>,
+    output_dir: String,
+) -> Result<DownloadResult, String> {
+    let parsed = Url::parse(&url).map_err(|e| format!("invalid URL: {}", e))?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err(format!("unsupported URL scheme: {}", parsed.scheme()));
+    }
+
+    let detected = detect_source(&parsed).ok_or_else(|| {
+        format!(
+            "unsupported host: {}",
+            parsed.host_str().unwrap_or("<unknown>")
+        )
+    })?;
+    let source = source.unwrap_or_else(|| detected.to_string());
+
+    let script = downloader_script_path(&app)?;
+    let output = Command::new("python3")
+        .arg(&script)
+        .arg(&source)
+        .arg(&url)
+        .arg(&output_dir)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
+}
@@ -0,0 +1,154 @@
+pub mod download;
+pub mod player;
+
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::{State, Window};
+
+use crate::database::{Database, Song};
+
+const HASH_CHUNK_BYTES: u64 = 64 * 1024;
+
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "wav", "flac", "ogg", "m4a", "aac"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioTrack {
+    pub path: String,
+    pub title: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ScanProgressPayload {
+    count: usize,
+    current_path: String,
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+fn title_from_path(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn walk(dir: &Path, window: &Window, count: &mut usize, tracks: &mut Vec<AudioTrack>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, window, count, tracks);
+        } else if is_audio_file(&path) {
+            *count += 1;
+            let path_string = path.to_string_lossy().to_string();
+            let _ = window.emit(
+                "music-scan-progress",
+                ScanProgressPayload {
+                    count: *count,
+                    current_path: path_string.clone(),
+                },
+            );
+            tracks.push(AudioTrack {
+                path: path_string,
+                title: title_from_path(&path),
+                artist: None,
+                album: None,
+            });
+        }
+    }
+}
+
+/// Recursively walks `root` for audio files, emitting music-scan-progress as each one
+/// is found so a large library doesn't freeze the UI with one giant blocking call, then
+/// emits music-scan-done once the walk finishes. Still returns the full track list for
+/// callers that don't care about progress.
+#[tauri::command]
+pub fn scan_music_folder(window: Window, root: String) -> Result<Vec<AudioTrack>, String> {
+    let root_path = PathBuf::from(&root);
+    if !root_path.is_dir() {
+        return Err(format!("{} is not a directory", root));
+    }
+
+    let mut tracks = Vec::new();
+    let mut count = 0usize;
+    walk(&root_path, &window, &mut count, &mut tracks);
+    let _ = window.emit("music-scan-done", tracks.len());
+    Ok(tracks)
+}
+
+/// Hashes a file's size plus its first and last 64KB — cheap enough to run over a
+/// whole library during import, but specific enough to catch the same file copied into
+/// two different folders without reading the entire (possibly large) file.
+fn content_hash(path: &Path) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|e| e.to_string())?;
+    let size = file.metadata().map_err(|e| e.to_string())?.len();
+
+    let head_len = HASH_CHUNK_BYTES.min(size) as usize;
+    let mut head = vec![0u8; head_len];
+    file.read_exact(&mut head).map_err(|e| e.to_string())?;
+
+    let mut tail = Vec::new();
+    if size > HASH_CHUNK_BYTES {
+        let tail_len = HASH_CHUNK_BYTES.min(size - head_len as u64) as usize;
+        file.seek(SeekFrom::End(-(tail_len as i64))).map_err(|e| e.to_string())?;
+        tail = vec![0u8; tail_len];
+        file.read_exact(&mut tail).map_err(|e| e.to_string())?;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    size.hash(&mut hasher);
+    head.hash(&mut hasher);
+    tail.hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportResult {
+    pub imported: Vec<Song>,
+    pub skipped_duplicates: usize,
+}
+
+/// Imports audio files into the songs table, skipping any whose content_hash already
+/// exists — e.g. the same file copied into two different folders — instead of creating
+/// a duplicate row for every original_path like before.
+#[tauri::command]
+pub fn process_import(
+    state: State<Database>,
+    paths: Vec<String>,
+    added_at: i64,
+) -> Result<ImportResult, String> {
+    let mut imported = Vec::new();
+    let mut skipped_duplicates = 0;
+
+    for path in paths {
+        let path_buf = PathBuf::from(&path);
+        let hash = content_hash(&path_buf)?;
+
+        if crate::database::find_song_by_content_hash(&state, &hash)?.is_some() {
+            skipped_duplicates += 1;
+            continue;
+        }
+
+        let title = title_from_path(&path_buf);
+        let song = crate::database::insert_song(&state, &title, &path, added_at, &hash)?;
+        imported.push(song);
+    }
+
+    Ok(ImportResult {
+        imported,
+        skipped_duplicates,
+    })
+}
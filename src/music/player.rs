@@ -0,0 +1,464 @@
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use serde::Serialize;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::mpsc::{self, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+const POSITION_EMIT_INTERVAL: Duration = Duration::from_millis(500);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlaybackState {
+    pub is_playing: bool,
+    pub position_seconds: f64,
+    pub duration_seconds: Option<f64>,
+    pub current_path: Option<String>,
+}
+
+enum AudioCommand {
+    Play(String),
+    /// Replaces the queue and starts playback at `start_index` in one message, so a
+    /// Next/Previous landing between a separate "set queue" and "play" message can't
+    /// race play_queue.
+    PlayQueue(Vec<String>, usize),
+    Next,
+    Previous,
+    Pause,
+    Resume,
+    Stop,
+    /// Carries a reply channel since `try_seek` can fail and the caller needs to know
+    /// whether the fallback re-decode also failed, rather than this being fire-and-forget
+    /// like the other commands.
+    Seek(f64, Sender<Result<(), String>>),
+    SetRepeat(RepeatMode),
+    SetShuffle(bool),
+    SetFadeDuration(Duration),
+}
+
+/// How long Stop ramps the volume down before actually stopping the sink. Independent
+/// of the configurable fade-in duration — an abrupt stop is jarring either way.
+const STOP_FADE_DURATION: Duration = Duration::from_millis(300);
+const STOP_FADE_STEPS: u32 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RepeatMode {
+    Off,
+    One,
+    All,
+}
+
+impl RepeatMode {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "off" => Ok(RepeatMode::Off),
+            "one" => Ok(RepeatMode::One),
+            "all" => Ok(RepeatMode::All),
+            other => Err(format!("unknown repeat mode: {}", other)),
+        }
+    }
+}
+
+/// Builds a random play order over `0..len`, swapping `avoid_first` out of the front
+/// slot if it lands there so shuffling doesn't immediately replay the track that just
+/// finished. Seeded from the clock rather than pulling in a `rand` dependency for one
+/// Fisher-Yates shuffle.
+fn build_shuffle_order(len: usize, avoid_first: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    let mut seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(1)
+        .max(1);
+
+    for i in (1..order.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let j = (seed as usize) % (i + 1);
+        order.swap(i, j);
+    }
+
+    if order.len() > 1 && order[0] == avoid_first {
+        order.swap(0, 1);
+    }
+    order
+}
+
+/// Picks the queue index to play next, honoring shuffle order and repeat mode. Shared
+/// by the explicit Next command and the natural end-of-track path so both advance the
+/// same way. Returns None when playback should stop.
+#[allow(clippy::too_many_arguments)]
+fn advance_index(
+    queue_len: usize,
+    current_index: usize,
+    shuffle: bool,
+    shuffle_order: &mut Vec<usize>,
+    shuffle_position: &mut usize,
+    repeat_mode: RepeatMode,
+) -> Option<usize> {
+    if queue_len == 0 {
+        return None;
+    }
+    if repeat_mode == RepeatMode::One {
+        return Some(current_index);
+    }
+
+    if shuffle {
+        if shuffle_order.len() != queue_len {
+            *shuffle_order = build_shuffle_order(queue_len, current_index);
+            *shuffle_position = usize::MAX;
+        }
+        if *shuffle_position == usize::MAX {
+            *shuffle_position = 0;
+            Some(shuffle_order[0])
+        } else if *shuffle_position + 1 < shuffle_order.len() {
+            *shuffle_position += 1;
+            Some(shuffle_order[*shuffle_position])
+        } else if repeat_mode == RepeatMode::All {
+            *shuffle_order = build_shuffle_order(queue_len, current_index);
+            *shuffle_position = 0;
+            Some(shuffle_order[0])
+        } else {
+            None
+        }
+    } else if current_index + 1 < queue_len {
+        Some(current_index + 1)
+    } else if repeat_mode == RepeatMode::All {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Stops whatever is currently playing and starts `path`, updating the shared state.
+/// Shared by Play/PlayQueue/Next/Previous so each doesn't reimplement sink teardown.
+/// `fade_duration` ramps the new source in rather than starting at full volume.
+fn play_path(
+    path: &str,
+    sink: &mut Option<Sink>,
+    stream_handle: &OutputStreamHandle,
+    thread_state: &Arc<Mutex<PlaybackState>>,
+    fade_duration: Duration,
+) {
+    if let Some(existing) = sink.take() {
+        existing.stop();
+    }
+    if let Ok(new_sink) = Sink::try_new(stream_handle) {
+        match File::open(path).map(BufReader::new).map(Decoder::new) {
+            Ok(Ok(source)) => {
+                new_sink.append(source.fade_in(fade_duration));
+                *sink = Some(new_sink);
+                let mut s = thread_state.lock().unwrap();
+                s.is_playing = true;
+                s.current_path = Some(path.to_string());
+                s.position_seconds = 0.0;
+            }
+            _ => {
+                *thread_state.lock().unwrap() = PlaybackState::default();
+            }
+        }
+    }
+}
+
+/// Ramps `sink`'s volume down to zero over `duration` before stopping it, so Stop
+/// doesn't cut playback off instantly.
+fn fade_out_and_stop(sink: Sink, duration: Duration) {
+    let start_volume = sink.volume();
+    let step_sleep = duration / STOP_FADE_STEPS;
+    for step in 1..=STOP_FADE_STEPS {
+        let fraction = 1.0 - (step as f32 / STOP_FADE_STEPS as f32);
+        sink.set_volume((start_volume * fraction).max(0.0));
+        std::thread::sleep(step_sleep);
+    }
+    sink.stop();
+}
+
+/// Reopens `path` and skips to `target`, replacing `sink`'s source. Fallback for
+/// `Sink::try_seek` failing, which it does for many MP3/OGG decoders.
+fn reload_at(
+    path: &str,
+    target: Duration,
+    sink: &mut Option<Sink>,
+    stream_handle: &OutputStreamHandle,
+) -> Result<(), String> {
+    let source = File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| e.to_string())
+        .and_then(|reader| Decoder::new(reader).map_err(|e| e.to_string()))?;
+    let new_sink = Sink::try_new(stream_handle).map_err(|e| e.to_string())?;
+    new_sink.append(source.skip_duration(target));
+    if let Some(old) = sink.take() {
+        old.stop();
+    }
+    *sink = Some(new_sink);
+    Ok(())
+}
+
+/// Drives rodio from a dedicated thread, since `Sink`/`OutputStream` aren't `Send`
+/// across the command boundary the way Tauri's async runtime would want. Commands
+/// come in over an mpsc channel; playback position and paused state are mirrored into
+/// `state` on every loop tick so `get_playback_state` never has to reach into the
+/// thread directly.
+pub struct AudioPlayer {
+    tx: Sender<AudioCommand>,
+    state: Arc<Mutex<PlaybackState>>,
+}
+
+impl AudioPlayer {
+    pub fn spawn(app: AppHandle) -> Self {
+        let (tx, rx) = mpsc::channel::<AudioCommand>();
+        let state = Arc::new(Mutex::new(PlaybackState::default()));
+        let thread_state = Arc::clone(&state);
+
+        std::thread::spawn(move || {
+            // _stream must stay alive for as long as anything is playing.
+            let (_stream, stream_handle) = match OutputStream::try_default() {
+                Ok(pair) => pair,
+                Err(e) => {
+                    let message = format!("failed to open an audio output device: {}", e);
+                    crate::logger::log(&format!("audio player: {}", message));
+                    let _ = app.emit_all("app-error", message);
+                    return;
+                }
+            };
+            let mut sink: Option<Sink> = None;
+            let mut last_emit = Instant::now();
+            let mut queue: Vec<String> = Vec::new();
+            let mut current_index: usize = 0;
+            let mut repeat_mode = RepeatMode::Off;
+            let mut shuffle = false;
+            let mut shuffle_order: Vec<usize> = Vec::new();
+            // usize::MAX means "order was just (re)built, nothing played from it yet" —
+            // advance_index treats that as "play order[0] next" instead of pre-incrementing.
+            let mut shuffle_position: usize = usize::MAX;
+            let mut fade_duration = Duration::ZERO;
+
+            loop {
+                match rx.try_recv() {
+                    Ok(AudioCommand::Play(path)) => {
+                        queue = vec![path.clone()];
+                        current_index = 0;
+                        play_path(&path, &mut sink, &stream_handle, &thread_state, fade_duration);
+                    }
+                    Ok(AudioCommand::PlayQueue(paths, start_index)) => {
+                        queue = paths;
+                        current_index = start_index.min(queue.len().saturating_sub(1));
+                        if let Some(path) = queue.get(current_index).cloned() {
+                            play_path(&path, &mut sink, &stream_handle, &thread_state, fade_duration);
+                        }
+                    }
+                    Ok(AudioCommand::Next) => {
+                        match advance_index(
+                            queue.len(),
+                            current_index,
+                            shuffle,
+                            &mut shuffle_order,
+                            &mut shuffle_position,
+                            repeat_mode,
+                        ) {
+                            Some(idx) => {
+                                current_index = idx;
+                                let path = queue[current_index].clone();
+                                play_path(&path, &mut sink, &stream_handle, &thread_state, fade_duration);
+                            }
+                            None => {
+                                if let Some(s) = sink.take() {
+                                    s.stop();
+                                }
+                                *thread_state.lock().unwrap() = PlaybackState::default();
+                            }
+                        }
+                    }
+                    Ok(AudioCommand::Previous) => {
+                        if current_index > 0 {
+                            current_index -= 1;
+                            let path = queue[current_index].clone();
+                            play_path(&path, &mut sink, &stream_handle, &thread_state, fade_duration);
+                        }
+                    }
+                    Ok(AudioCommand::Pause) => {
+                        if let Some(sink) = &sink {
+                            sink.pause();
+                        }
+                    }
+                    Ok(AudioCommand::Resume) => {
+                        if let Some(sink) = &sink {
+                            sink.play();
+                        }
+                    }
+                    Ok(AudioCommand::Stop) => {
+                        *thread_state.lock().unwrap() = PlaybackState::default();
+                        if let Some(active) = sink.take() {
+                            fade_out_and_stop(active, STOP_FADE_DURATION);
+                        }
+                    }
+                    Ok(AudioCommand::Seek(seconds, reply)) => {
+                        let target = Duration::from_secs_f64(seconds.max(0.0));
+                        let needs_reload = match sink.as_ref() {
+                            None => {
+                                let _ = reply.send(Err("no track is currently loaded".to_string()));
+                                false
+                            }
+                            Some(active) => match active.try_seek(target) {
+                                Ok(()) => {
+                                    let _ = reply.send(Ok(()));
+                                    false
+                                }
+                                Err(_) => true,
+                            },
+                        };
+
+                        if needs_reload {
+                            let current_path = thread_state.lock().unwrap().current_path.clone();
+                            let result = match current_path {
+                                Some(path) => reload_at(&path, target, &mut sink, &stream_handle),
+                                None => Err("no track is currently loaded".to_string()),
+                            };
+                            if result.is_ok() {
+                                thread_state.lock().unwrap().position_seconds = seconds.max(0.0);
+                            }
+                            let _ = reply.send(result);
+                        }
+                    }
+                    Ok(AudioCommand::SetRepeat(mode)) => {
+                        repeat_mode = mode;
+                    }
+                    Ok(AudioCommand::SetShuffle(enabled)) => {
+                        shuffle = enabled;
+                        if shuffle && !queue.is_empty() {
+                            shuffle_order = build_shuffle_order(queue.len(), current_index);
+                            shuffle_position = usize::MAX;
+                        }
+                    }
+                    Ok(AudioCommand::SetFadeDuration(duration)) => {
+                        fade_duration = duration;
+                    }
+                    Err(TryRecvError::Empty) => {}
+                    Err(TryRecvError::Disconnected) => break,
+                }
+
+                let finished_naturally = sink.as_ref().map(|s| s.empty()).unwrap_or(false);
+                if finished_naturally {
+                    // A manual Stop already clears current_path and takes the sink
+                    // before this tick runs, so by the time we get here with a sink
+                    // that just went empty, it was always a natural end-of-track —
+                    // never fired for an explicit stop.
+                    let finished_path = thread_state.lock().unwrap().current_path.clone();
+
+                    match advance_index(
+                        queue.len(),
+                        current_index,
+                        shuffle,
+                        &mut shuffle_order,
+                        &mut shuffle_position,
+                        repeat_mode,
+                    ) {
+                        Some(idx) => {
+                            current_index = idx;
+                            let path = queue[current_index].clone();
+                            play_path(&path, &mut sink, &stream_handle, &thread_state, fade_duration);
+                        }
+                        None => {
+                            sink = None;
+                            *thread_state.lock().unwrap() = PlaybackState::default();
+                        }
+                    }
+
+                    if let Some(path) = finished_path {
+                        let _ = app.emit_all("track-finished", path);
+                    }
+                } else if let Some(active) = &sink {
+                    let mut s = thread_state.lock().unwrap();
+                    s.position_seconds = active.get_pos().as_secs_f64();
+                    s.is_playing = !active.is_paused();
+                }
+
+                if last_emit.elapsed() >= POSITION_EMIT_INTERVAL {
+                    let snapshot = thread_state.lock().unwrap().clone();
+                    let _ = app.emit_all("playback-position", snapshot);
+                    last_emit = Instant::now();
+                }
+
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        });
+
+        AudioPlayer { tx, state }
+    }
+
+    fn send(&self, command: AudioCommand) -> Result<(), String> {
+        self.tx.send(command).map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub fn play_audio(player: State<AudioPlayer>, path: String) -> Result<(), String> {
+    player.send(AudioCommand::Play(path))
+}
+
+#[tauri::command]
+pub fn play_queue(player: State<AudioPlayer>, paths: Vec<String>, start_index: usize) -> Result<(), String> {
+    player.send(AudioCommand::PlayQueue(paths, start_index))
+}
+
+#[tauri::command]
+pub fn next_track(player: State<AudioPlayer>) -> Result<(), String> {
+    player.send(AudioCommand::Next)
+}
+
+#[tauri::command]
+pub fn previous_track(player: State<AudioPlayer>) -> Result<(), String> {
+    player.send(AudioCommand::Previous)
+}
+
+#[tauri::command]
+pub fn pause_audio(player: State<AudioPlayer>) -> Result<(), String> {
+    player.send(AudioCommand::Pause)
+}
+
+#[tauri::command]
+pub fn resume_audio(player: State<AudioPlayer>) -> Result<(), String> {
+    player.send(AudioCommand::Resume)
+}
+
+#[tauri::command]
+pub fn stop_audio(player: State<AudioPlayer>) -> Result<(), String> {
+    player.send(AudioCommand::Stop)
+}
+
+/// Seeks to `seconds`, falling back to reopening the current file and skipping ahead
+/// when the decoder doesn't support `try_seek` (several MP3/OGG decoders don't), and
+/// reports back whether that fallback also failed rather than swallowing the error.
+#[tauri::command]
+pub fn seek_audio(player: State<AudioPlayer>, seconds: f64) -> Result<(), String> {
+    let (reply_tx, reply_rx) = mpsc::channel();
+    player.send(AudioCommand::Seek(seconds, reply_tx))?;
+    reply_rx.recv().map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub fn set_repeat(player: State<AudioPlayer>, mode: String) -> Result<(), String> {
+    let parsed = RepeatMode::parse(&mode)?;
+    player.send(AudioCommand::SetRepeat(parsed))
+}
+
+#[tauri::command]
+pub fn set_shuffle(player: State<AudioPlayer>, enabled: bool) -> Result<(), String> {
+    player.send(AudioCommand::SetShuffle(enabled))
+}
+
+/// Sets how long newly-started tracks fade in for. 0 (the default) disables fading.
+#[tauri::command]
+pub fn set_fade_duration(player: State<AudioPlayer>, ms: u64) -> Result<(), String> {
+    player.send(AudioCommand::SetFadeDuration(Duration::from_millis(ms)))
+}
+
+/// Reads the playback snapshot the player thread keeps up to date, so the frontend's
+/// seek bar has somewhere to poll from beyond just the playback-position event.
+#[tauri::command]
+pub fn get_playback_state(player: State<AudioPlayer>) -> PlaybackState {
+    player.state.lock().unwrap().clone()
+}
@@ -0,0 +1,47 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+use super::{OcrResult, PreprocessOptions};
+
+fn cache() -> &'static Mutex<HashMap<u64, OcrResult>> {
+    static CACHE: OnceLock<Mutex<HashMap<u64, OcrResult>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Hashes everything that affects the OCR result for a given image — the image bytes
+/// themselves plus the language, preprocessing options, confidence threshold, and
+/// auto-rotate flag — so a
+/// cache hit only ever returns a result that was actually produced for this exact input.
+/// No new hashing dependency: `DefaultHasher` is plenty for a cache key, same reasoning
+/// as the content hash used for song import dedup.
+pub fn cache_key(
+    image_base64: &str,
+    language: Option<&str>,
+    preprocess: &Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: bool,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    image_base64.hash(&mut hasher);
+    language.hash(&mut hasher);
+    serde_json::to_string(preprocess).unwrap_or_default().hash(&mut hasher);
+    min_confidence.map(|v| v.to_bits()).hash(&mut hasher);
+    auto_rotate.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub fn get(key: u64) -> Option<OcrResult> {
+    cache().lock().unwrap().get(&key).cloned()
+}
+
+pub fn put(key: u64, result: OcrResult) {
+    cache().lock().unwrap().insert(key, result);
+}
+
+/// Drops every cached OCR result, e.g. after the recognizer or its language data changes.
+#[tauri::command]
+pub fn clear_ocr_cache() {
+    cache().lock().unwrap().clear();
+}
@@ -0,0 +1,40 @@
+use arboard::Clipboard;
+use image::{DynamicImage, RgbaImage};
+
+use super::{encode_png, perform_ocr_native, OcrResult, PreprocessOptions};
+
+/// Runs OCR on whatever image is currently on the system clipboard, so a plain
+/// copy-image-then-paste gesture works the same way on Windows, macOS, and Linux
+/// instead of only through the Windows-specific snipping tool capture. Returns an
+/// empty result (rather than an error) when the clipboard holds no image, since "no
+/// image to OCR" isn't a failure, just nothing to do.
+#[tauri::command]
+pub fn ocr_from_clipboard(
+    language: Option<String>,
+    preprocess: Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: Option<bool>,
+) -> Result<OcrResult, String> {
+    let mut clipboard = Clipboard::new().map_err(|e| e.to_string())?;
+    let image_data = match clipboard.get_image() {
+        Ok(data) => data,
+        Err(_) => {
+            return Ok(OcrResult {
+                text: String::new(),
+                confidence: 0.0,
+                words: None,
+                processing_time_ms: None,
+            })
+        }
+    };
+
+    let buffer = RgbaImage::from_raw(
+        image_data.width as u32,
+        image_data.height as u32,
+        image_data.bytes.into_owned(),
+    )
+    .ok_or_else(|| "clipboard image had an unexpected byte layout".to_string())?;
+
+    let image_base64 = encode_png(DynamicImage::ImageRgba8(buffer))?;
+    perform_ocr_native(image_base64, language, preprocess, min_confidence, auto_rotate)
+}
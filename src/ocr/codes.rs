@@ -0,0 +1,52 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use super::BoundingBox;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeResult {
+    pub code_type: String,
+    pub value: String,
+    pub bbox: BoundingBox,
+}
+
+/// Detects and decodes QR codes in a base64-encoded image, run on the same decoded
+/// image OCR uses, so the frontend can offer "Open link" for a code instead of
+/// surfacing whatever gibberish OCR reads off of it. Uses `rqrr`, a pure-Rust decoder,
+/// to avoid pulling in a native barcode library.
+#[tauri::command]
+pub fn scan_codes(image_data: String) -> Result<Vec<CodeResult>, String> {
+    let bytes = STANDARD.decode(image_data.trim()).map_err(|e| e.to_string())?;
+    let img = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
+    let luma = img.to_luma8();
+
+    let mut prepared = rqrr::PreparedImage::prepare(luma);
+    let grids = prepared.detect_grids();
+
+    let mut results = Vec::new();
+    for grid in grids {
+        let Ok((_meta, content)) = grid.decode() else {
+            continue;
+        };
+
+        let xs: Vec<i32> = grid.bounds.iter().map(|p| p.x).collect();
+        let ys: Vec<i32> = grid.bounds.iter().map(|p| p.y).collect();
+        let min_x = *xs.iter().min().unwrap_or(&0);
+        let max_x = *xs.iter().max().unwrap_or(&0);
+        let min_y = *ys.iter().min().unwrap_or(&0);
+        let max_y = *ys.iter().max().unwrap_or(&0);
+
+        results.push(CodeResult {
+            code_type: "qr".to_string(),
+            value: content,
+            bbox: BoundingBox {
+                x: min_x as f64,
+                y: min_y as f64,
+                width: (max_x - min_x) as f64,
+                height: (max_y - min_y) as f64,
+            },
+        });
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,272 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, ClipboardManager, Manager, Window};
+
+use crate::database::Database;
+
+mod cache;
+mod clipboard;
+mod codes;
+mod preprocess;
+mod rotation;
+mod table;
+
+pub use cache::clear_ocr_cache;
+pub use clipboard::ocr_from_clipboard;
+pub use codes::scan_codes;
+pub use preprocess::PreprocessOptions;
+pub use table::reconstruct_table;
+
+#[cfg(target_os = "windows")]
+mod windows_ocr;
+
+#[cfg(not(target_os = "windows"))]
+mod tesseract;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrWord {
+    pub text: String,
+    pub bbox: BoundingBox,
+    /// Recognizer confidence for this word, 0.0-1.0. Windows.Media.Ocr doesn't expose a
+    /// per-word score at all, so words from that recognizer are always 1.0; Tesseract's
+    /// TSV output gives a real per-word value.
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrResult {
+    pub text: String,
+    pub confidence: f32,
+    /// Per-word positions, used to draw a selectable overlay and let users copy a
+    /// single word. Kept optional so callers that only need the flat text aren't
+    /// broken if a recognizer can't report positions.
+    pub words: Option<Vec<OcrWord>>,
+    /// Wall-clock time the recognizer itself took, in milliseconds. `None` when the
+    /// result came from the cache, since re-serving a cached result doesn't reflect how
+    /// long recognition actually took. The frontend forwards this straight into
+    /// `OcrRecord.processing_time_ms` when saving a capture to history.
+    pub processing_time_ms: Option<i64>,
+}
+
+fn encode_png(img: image::DynamicImage) -> Result<String, String> {
+    let mut png_bytes = Vec::new();
+    img.write_to(&mut std::io::Cursor::new(&mut png_bytes), image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(STANDARD.encode(&png_bytes))
+}
+
+/// Runs OCR on a base64-encoded image using the platform's native text recognizer.
+/// `language` is passed to the recognizer as is: a BCP-47 tag (e.g. "zh-Hans") for
+/// Windows OCR, which falls back to the user's installed profile languages when it is
+/// unset, or a Tesseract language code, which defaults to "eng". `preprocess` optionally grayscales,
+/// adjusts contrast, and/or binarizes the image first, which helps accuracy on
+/// low-contrast or colored-background screenshots.
+///
+/// Results are cached in memory keyed by a hash of the image bytes plus `language`,
+/// `preprocess`, `min_confidence`, and `auto_rotate`, so re-OCRing the same capture with the same
+/// options (e.g. reopening a history item) is instant instead of redoing the
+/// recognition work. See `clear_ocr_cache`.
+///
+/// `min_confidence` (0.0-1.0) drops words below the threshold before `text` is joined,
+/// so a caller can treat low-confidence words as if they weren't recognized at all.
+/// `OcrResult.confidence` is always the real aggregate over whatever words remain, not
+/// a placeholder, so the UI can warn on a low-confidence result even without a filter.
+///
+/// `auto_rotate` tries recognition at 0/90/180/270 degrees and keeps whichever rotation
+/// reads the most text, for photos of documents that weren't shot upright. See
+/// `rotation::recognize_best_rotation`.
+#[tauri::command]
+pub fn perform_ocr_native(
+    image_base64: String,
+    language: Option<String>,
+    preprocess: Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: Option<bool>,
+) -> Result<OcrResult, String> {
+    let key = cache::cache_key(&image_base64, language.as_deref(), &preprocess, min_confidence, auto_rotate.unwrap_or(false));
+    if let Some(mut cached) = cache::get(key) {
+        cached.processing_time_ms = None;
+        return Ok(cached);
+    }
+
+    let processed_base64 = match preprocess {
+        Some(options) => {
+            let bytes = STANDARD
+                .decode(image_base64.trim())
+                .map_err(|e| e.to_string())?;
+            let img = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
+            encode_png(self::preprocess::preprocess(img, &options))?
+        }
+        None => image_base64,
+    };
+
+    let started = std::time::Instant::now();
+    let recognized = if auto_rotate.unwrap_or(false) {
+        let bytes = STANDARD
+            .decode(processed_base64.trim())
+            .map_err(|e| e.to_string())?;
+        let decoded = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
+        rotation::recognize_best_rotation(&processed_base64, decoded, language.as_deref())
+    } else {
+        recognize_dispatch(&processed_base64, language.as_deref())
+    };
+    let mut result = recognized.map_err(|e| {
+        crate::logger::log(&format!("ocr: recognition failed: {}", e));
+        e
+    })?;
+    result.processing_time_ms = Some(started.elapsed().as_millis() as i64);
+    apply_confidence(&mut result, min_confidence);
+
+    cache::put(key, result.clone());
+    Ok(result)
+}
+
+/// Runs the platform recognizer directly, with no caching, rotation search, or
+/// confidence filtering — just `windows_ocr` or `tesseract` depending on target.
+/// `rotation::recognize_best_rotation` calls this once per candidate rotation.
+pub(crate) fn recognize_dispatch(image_base64: &str, language: Option<&str>) -> Result<OcrResult, String> {
+    #[cfg(target_os = "windows")]
+    {
+        windows_ocr::recognize_text(image_base64, language)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        tesseract::recognize_text(image_base64, language)
+    }
+}
+
+/// Recomputes `OcrResult.confidence` as the average over `words` (when the recognizer
+/// reported any), dropping words below `min_confidence` first and rejoining `text` from
+/// what's left. A `None` threshold still recomputes the aggregate — it just doesn't drop
+/// anything. `text` is only rebuilt when the retain actually removed a word: rejoining
+/// unconditionally would flatten a recognizer's line/paragraph breaks (as Windows OCR's
+/// `Text()` preserves them) even when nothing was filtered, e.g. for `Some(0.0)`.
+fn apply_confidence(result: &mut OcrResult, min_confidence: Option<f64>) {
+    let Some(words) = result.words.as_mut() else { return };
+    if let Some(threshold) = min_confidence {
+        let before = words.len();
+        words.retain(|word| word.confidence as f64 >= threshold);
+        if words.len() != before {
+            result.text = words.iter().map(|word| word.text.as_str()).collect::<Vec<_>>().join(" ");
+        }
+    }
+    result.confidence = if words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(|word| word.confidence).sum::<f32>() / words.len() as f32
+    };
+}
+
+fn auto_copy_enabled(app: &AppHandle) -> bool {
+    crate::database::get_setting(app.state::<Database>(), "auto_copy_ocr".to_string())
+        .ok()
+        .flatten()
+        .map(|setting| setting.value == "true")
+        .unwrap_or(false)
+}
+
+/// Same as `perform_ocr_native`, but also copies the recognized text to the system
+/// clipboard when the `auto_copy_ocr` setting is enabled, so capture → paste elsewhere
+/// doesn't need a manual copy step in between. Skips the copy for an empty result so a
+/// capture that found no text doesn't clobber whatever the user had on the clipboard.
+#[tauri::command]
+pub fn perform_ocr_native_and_copy(
+    app: AppHandle,
+    image_base64: String,
+    language: Option<String>,
+    preprocess: Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: Option<bool>,
+) -> Result<OcrResult, String> {
+    let result = perform_ocr_native(image_base64, language, preprocess, min_confidence, auto_rotate)?;
+    if !result.text.is_empty() && auto_copy_enabled(&app) {
+        let _ = app.clipboard_manager().write_text(result.text.clone());
+    }
+    Ok(result)
+}
+
+/// Reads an image straight from disk instead of making the frontend read and base64-encode
+/// it first. Re-encodes through the `image` crate to PNG so the OCR engine always sees a
+/// format it understands, regardless of the source file's format.
+#[tauri::command]
+pub fn perform_ocr_file(
+    path: String,
+    language: Option<String>,
+    preprocess: Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: Option<bool>,
+) -> Result<OcrResult, String> {
+    let img = image::open(&path).map_err(|e| e.to_string())?;
+    let image_base64 = encode_png(img)?;
+    perform_ocr_native(image_base64, language, preprocess, min_confidence, auto_rotate)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchOcrResult {
+    pub path: String,
+    pub result: Option<OcrResult>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchProgressPayload {
+    index: usize,
+    total: usize,
+}
+
+/// OCRs a whole folder's worth of images in one call. Runs sequentially (not in
+/// parallel) so the emitted ocr-batch-progress events stay in order, and a failure on
+/// one file produces an error entry instead of aborting the rest of the batch.
+#[tauri::command]
+pub fn perform_ocr_batch(
+    window: Window,
+    paths: Vec<String>,
+    language: Option<String>,
+    preprocess: Option<PreprocessOptions>,
+    min_confidence: Option<f64>,
+    auto_rotate: Option<bool>,
+) -> Vec<BatchOcrResult> {
+    let total = paths.len();
+    let mut results = Vec::with_capacity(total);
+
+    for (index, path) in paths.into_iter().enumerate() {
+        let _ = window.emit("ocr-batch-progress", BatchProgressPayload { index, total });
+
+        match perform_ocr_file(path.clone(), language.clone(), preprocess.clone(), min_confidence, auto_rotate) {
+            Ok(result) => results.push(BatchOcrResult {
+                path,
+                result: Some(result),
+                error: None,
+            }),
+            Err(error) => results.push(BatchOcrResult {
+                path,
+                result: None,
+                error: Some(error),
+            }),
+        }
+    }
+
+    results
+}
+
+/// Lists the OCR-capable languages installed on this machine. Only Windows OCR is
+/// queried; on other platforms this returns an empty list.
+#[tauri::command]
+pub fn list_ocr_languages() -> Result<Vec<String>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        windows_ocr::list_ocr_languages()
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        Ok(Vec::new())
+    }
+}
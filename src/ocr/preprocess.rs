@@ -0,0 +1,40 @@
+use image::DynamicImage;
+use serde::{Deserialize, Serialize};
+
+/// Toggles for the preprocessing pipeline. Everything defaults to off so callers get
+/// the raw image unless they explicitly opt in, which makes it easy to compare raw vs.
+/// processed results from the UI.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PreprocessOptions {
+    #[serde(default)]
+    pub grayscale: bool,
+    #[serde(default)]
+    pub contrast: Option<f32>,
+    #[serde(default)]
+    pub binarize_threshold: Option<u8>,
+}
+
+/// Applies grayscale, contrast, and binarization (in that order) to help OCR on
+/// low-contrast or colored-background screenshots. Binarizing implies grayscale first,
+/// since thresholding only makes sense on a single luma channel.
+pub fn preprocess(img: DynamicImage, options: &PreprocessOptions) -> DynamicImage {
+    let mut img = img;
+
+    if options.grayscale || options.binarize_threshold.is_some() {
+        img = DynamicImage::ImageLuma8(img.to_luma8());
+    }
+
+    if let Some(contrast) = options.contrast {
+        img = img.adjust_contrast(contrast);
+    }
+
+    if let Some(threshold) = options.binarize_threshold {
+        let mut luma = img.to_luma8();
+        for pixel in luma.pixels_mut() {
+            pixel.0[0] = if pixel.0[0] < threshold { 0 } else { 255 };
+        }
+        img = DynamicImage::ImageLuma8(luma);
+    }
+
+    img
+}
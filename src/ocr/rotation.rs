@@ -0,0 +1,83 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+use image::DynamicImage;
+
+use super::{encode_png, recognize_dispatch, OcrResult};
+
+fn angle_cache() -> &'static Mutex<HashMap<u64, u32>> {
+    static ANGLE_CACHE: OnceLock<Mutex<HashMap<u64, u32>>> = OnceLock::new();
+    ANGLE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn image_hash(image_base64: &str, language: Option<&str>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    image_base64.hash(&mut hasher);
+    language.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn rotate(img: &DynamicImage, angle: u32) -> DynamicImage {
+    match angle {
+        90 => img.rotate90(),
+        180 => img.rotate180(),
+        270 => img.rotate270(),
+        _ => img.clone(),
+    }
+}
+
+/// Score a candidate recognition: more non-whitespace characters wins first, with
+/// confidence as a tiebreaker only when character counts are equal (e.g. a sideways
+/// page that still picks up a few stray glyphs at every angle).
+fn score(result: &OcrResult) -> (usize, f32) {
+    let chars = result.text.chars().filter(|c| !c.is_whitespace()).count();
+    (chars, result.confidence)
+}
+
+/// Tries recognition at 0/90/180/270 degrees and keeps whichever rotation reads the
+/// most text, for photos of documents that weren't shot upright. The winning angle is
+/// cached per image so a repeat OCR pass on the same capture skips straight to it
+/// instead of re-running the recognizer four times; the full `OcrResult` itself is
+/// already cached one layer up in `cache`, keyed on `auto_rotate`.
+pub fn recognize_best_rotation(
+    image_base64: &str,
+    decoded: DynamicImage,
+    language: Option<&str>,
+) -> Result<OcrResult, String> {
+    let key = image_hash(image_base64, language);
+
+    if let Some(&angle) = angle_cache().lock().unwrap().get(&key) {
+        let candidate = if angle == 0 {
+            image_base64.to_string()
+        } else {
+            encode_png(rotate(&decoded, angle))?
+        };
+        return recognize_dispatch(&candidate, language);
+    }
+
+    let mut best: Option<(u32, OcrResult)> = None;
+    for angle in [0, 90, 180, 270] {
+        let candidate = if angle == 0 {
+            image_base64.to_string()
+        } else {
+            encode_png(rotate(&decoded, angle))?
+        };
+        let result = match recognize_dispatch(&candidate, language) {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+        let better = match &best {
+            Some((_, current_best)) => score(&result) > score(current_best),
+            None => true,
+        };
+        if better {
+            best = Some((angle, result));
+        }
+    }
+
+    let (angle, result) = best.ok_or_else(|| "OCR recognition failed at every rotation".to_string())?;
+    angle_cache().lock().unwrap().insert(key, angle);
+    Ok(result)
+}
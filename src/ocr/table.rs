@@ -0,0 +1,72 @@
+use super::OcrWord;
+
+/// Clusters words into rows by vertical overlap and into columns by horizontal gaps,
+/// rebuilding the grid structure that flattening to plain text throws away — useful for
+/// screenshots of spreadsheets and other tabular data. Returns one cell per row/column,
+/// left empty where a row has no word in that column; multiple words landing in the
+/// same cell are joined with a space.
+#[tauri::command]
+pub fn reconstruct_table(words: Vec<OcrWord>) -> Vec<Vec<String>> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut sorted = words;
+    sorted.sort_by(|a, b| a.bbox.y.partial_cmp(&b.bbox.y).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut rows: Vec<Vec<OcrWord>> = Vec::new();
+    for word in sorted {
+        let center_y = word.bbox.y + word.bbox.height / 2.0;
+        let existing_row = rows.iter_mut().find(|row| {
+            let row_top = row.iter().map(|w| w.bbox.y).fold(f64::MAX, f64::min);
+            let row_bottom = row.iter().map(|w| w.bbox.y + w.bbox.height).fold(f64::MIN, f64::max);
+            center_y >= row_top && center_y <= row_bottom
+        });
+        match existing_row {
+            Some(row) => row.push(word),
+            None => rows.push(vec![word]),
+        }
+    }
+
+    for row in &mut rows {
+        row.sort_by(|a, b| a.bbox.x.partial_cmp(&b.bbox.x).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    // Column boundaries: cluster every word's x-center across the whole table, splitting
+    // wherever the gap between consecutive centers is wider than the typical word — a
+    // bigger jump than any normal space within a column.
+    let mut centers: Vec<f64> = rows.iter().flatten().map(|w| w.bbox.x + w.bbox.width / 2.0).collect();
+    centers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut widths: Vec<f64> = rows.iter().flatten().map(|w| w.bbox.width).collect();
+    widths.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median_width = widths.get(widths.len() / 2).copied().unwrap_or(1.0);
+    let gap_threshold = median_width * 1.5;
+
+    let mut boundaries: Vec<f64> = Vec::new();
+    for i in 1..centers.len() {
+        if centers[i] - centers[i - 1] > gap_threshold {
+            boundaries.push((centers[i] + centers[i - 1]) / 2.0);
+        }
+    }
+
+    let column_count = boundaries.len() + 1;
+    let column_for = |x_center: f64| boundaries.iter().take_while(|&&b| x_center > b).count();
+
+    rows.iter()
+        .map(|row| {
+            let mut cells = vec![String::new(); column_count];
+            for word in row {
+                let center_x = word.bbox.x + word.bbox.width / 2.0;
+                let cell = &mut cells[column_for(center_x)];
+                if cell.is_empty() {
+                    *cell = word.text.clone();
+                } else {
+                    cell.push(' ');
+                    cell.push_str(&word.text);
+                }
+            }
+            cells
+        })
+        .collect()
+}
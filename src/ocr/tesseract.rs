@@ -0,0 +1,102 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::ErrorKind;
+use std::process::Command;
+
+use super::{BoundingBox, OcrResult, OcrWord};
+
+/// Shells out to the `tesseract` CLI: the decoded image is written to a temp PNG, then
+/// `tesseract <png> stdout -l <lang> tsv` is run. TSV mode (rather than plain `stdout`
+/// text) gives a bounding box and confidence per word instead of just a flat string,
+/// which `perform_ocr_native` needs to support `min_confidence` filtering.
+pub fn recognize_text(image_base64: &str, language: Option<&str>) -> Result<OcrResult, String> {
+    let bytes = STANDARD
+        .decode(image_base64.trim())
+        .map_err(|e| e.to_string())?;
+
+    let tmp_path = std::env::temp_dir().join(format!("ask_ocr_{}.png", std::process::id()));
+    std::fs::write(&tmp_path, &bytes).map_err(|e| e.to_string())?;
+
+    let lang = language.unwrap_or("eng");
+    let output = Command::new("tesseract")
+        .arg(&tmp_path)
+        .arg("stdout")
+        .arg("-l")
+        .arg(lang)
+        .arg("tsv")
+        .output();
+
+    let _ = std::fs::remove_file(&tmp_path);
+
+    let output = match output {
+        Ok(output) => output,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return Err(
+                "tesseract is not installed or not on PATH (try `apt install tesseract-ocr` \
+                 or `brew install tesseract`)"
+                    .to_string(),
+            );
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let tsv = String::from_utf8_lossy(&output.stdout);
+    let words = parse_tsv_words(&tsv);
+    let text = words.iter().map(|w| w.text.as_str()).collect::<Vec<_>>().join(" ");
+    let confidence = if words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(|w| w.confidence).sum::<f32>() / words.len() as f32
+    };
+
+    Ok(OcrResult {
+        text,
+        confidence,
+        words: Some(words),
+        processing_time_ms: None,
+    })
+}
+
+/// Parses `tesseract ... tsv` output. Each data row is tab-separated:
+/// `level page_num block_num par_num line_num word_num left top width height conf text`.
+/// Only level-5 (word) rows carry real text and a non-negative confidence; everything
+/// above that (page/block/par/line) is a summary row tesseract emits alongside the words.
+fn parse_tsv_words(tsv: &str) -> Vec<OcrWord> {
+    let mut words = Vec::new();
+    for line in tsv.lines().skip(1) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 12 || fields[0] != "5" {
+            continue;
+        }
+
+        let text = fields[11].trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let (Ok(left), Ok(top), Ok(width), Ok(height), Ok(conf)) = (
+            fields[6].parse::<f64>(),
+            fields[7].parse::<f64>(),
+            fields[8].parse::<f64>(),
+            fields[9].parse::<f64>(),
+            fields[10].parse::<f32>(),
+        ) else {
+            continue;
+        };
+
+        words.push(OcrWord {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                x: left,
+                y: top,
+                width,
+                height,
+            },
+            confidence: (conf / 100.0).clamp(0.0, 1.0),
+        });
+    }
+    words
+}
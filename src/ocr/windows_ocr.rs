@@ -0,0 +1,99 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use windows::Globalization::Language;
+use windows::Graphics::Imaging::BitmapDecoder;
+use windows::Media::Ocr::OcrEngine;
+use windows::Storage::Streams::{DataWriter, InMemoryRandomAccessStream};
+
+use super::{BoundingBox, OcrResult, OcrWord};
+
+/// Lists the BCP-47 tags of every OCR-capable language installed on this machine, so
+/// the UI can offer a real picker instead of guessing what's available.
+pub fn list_ocr_languages() -> Result<Vec<String>, String> {
+    let languages = OcrEngine::AvailableRecognizerLanguages().map_err(|e| e.to_string())?;
+    let mut tags = Vec::new();
+    for language in languages.into_iter() {
+        tags.push(language.LanguageTag().map_err(|e| e.to_string())?.to_string());
+    }
+    Ok(tags)
+}
+
+fn create_engine(language: Option<&str>) -> Result<OcrEngine, String> {
+    match language {
+        Some(tag) => {
+            let language =
+                Language::CreateLanguage(&tag.into()).map_err(|e| e.to_string())?;
+            OcrEngine::TryCreateFromLanguage(&language)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("OCR language '{}' is not installed", tag))
+        }
+        None => OcrEngine::TryCreateFromUserProfileLanguages()
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "no OCR language available in the user profile".to_string()),
+    }
+}
+
+/// Decodes the image, runs it through the Windows.Media.Ocr engine for `language` (or
+/// the user's profile languages when unset), and flattens the line/word hierarchy into
+/// a flat `text` plus per-word bounding boxes read from each `OcrWord`'s `BoundingRect`.
+pub fn recognize_text(image_base64: &str, language: Option<&str>) -> Result<OcrResult, String> {
+    let bytes = STANDARD
+        .decode(image_base64.trim())
+        .map_err(|e| e.to_string())?;
+
+    let stream = InMemoryRandomAccessStream::new().map_err(|e| e.to_string())?;
+    let writer = DataWriter::CreateDataWriter(&stream).map_err(|e| e.to_string())?;
+    writer.WriteBytes(&bytes).map_err(|e| e.to_string())?;
+    writer
+        .StoreAsync()
+        .map_err(|e| e.to_string())?
+        .get()
+        .map_err(|e| e.to_string())?;
+    stream.Seek(0).map_err(|e| e.to_string())?;
+
+    let decoder = BitmapDecoder::CreateAsync(&stream)
+        .map_err(|e| e.to_string())?
+        .get()
+        .map_err(|e| e.to_string())?;
+    let bitmap = decoder
+        .GetSoftwareBitmapAsync()
+        .map_err(|e| e.to_string())?
+        .get()
+        .map_err(|e| e.to_string())?;
+
+    let engine = create_engine(language)?;
+
+    let ocr_result = engine
+        .RecognizeAsync(&bitmap)
+        .map_err(|e| e.to_string())?
+        .get()
+        .map_err(|e| e.to_string())?;
+
+    let text = ocr_result.Text().map_err(|e| e.to_string())?.to_string();
+
+    let mut words = Vec::new();
+    for line in ocr_result.Lines().map_err(|e| e.to_string())?.into_iter() {
+        for word in line.Words().map_err(|e| e.to_string())?.into_iter() {
+            let word_text = word.Text().map_err(|e| e.to_string())?.to_string();
+            let rect = word.BoundingRect().map_err(|e| e.to_string())?;
+            words.push(OcrWord {
+                text: word_text,
+                bbox: BoundingBox {
+                    x: rect.X as f64,
+                    y: rect.Y as f64,
+                    width: rect.Width as f64,
+                    height: rect.Height as f64,
+                },
+                // Windows.Media.Ocr doesn't report a per-word score, unlike Tesseract's
+                // TSV output; treat every word as fully confident rather than guessing.
+                confidence: 1.0,
+            });
+        }
+    }
+
+    Ok(OcrResult {
+        text,
+        confidence: 1.0,
+        words: Some(words),
+        processing_time_ms: None,
+    })
+}
@@ -0,0 +1,144 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Window};
+
+use super::config::ollama_base_url;
+use super::detector::ensure_ollama_running;
+use super::legacy::GenerateOptions;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    message: ChatMessage,
+}
+
+/// Non-streaming chat completion over `/api/chat`, which takes the full message
+/// history instead of a single prompt. Lets the assistant answer follow-ups like "now
+/// summarize that in French" that need prior turns in context.
+#[tauri::command]
+pub async fn ollama_chat(
+    app: AppHandle,
+    model: String,
+    messages: Vec<ChatMessage>,
+    options: Option<GenerateOptions>,
+) -> Result<ChatMessage, String> {
+    ensure_ollama_running(&app).await?;
+
+    let mut body = json!({
+        "model": model,
+        "messages": messages,
+        "stream": false,
+    });
+    if let Some(options) = options {
+        body["options"] = json!(options);
+    }
+
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/chat", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let parsed: ChatResponse = resp.json().await.map_err(|e| e.to_string())?;
+    Ok(parsed.message)
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChunk {
+    message: ChatMessage,
+    #[serde(default)]
+    done: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ChatTokenPayload<'a> {
+    request_id: &'a str,
+    token: &'a str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ChatDonePayload<'a> {
+    request_id: &'a str,
+}
+
+fn new_chat_request_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("chat-{}", nanos)
+}
+
+/// Streams assistant message tokens from `/api/chat` as they arrive, mirroring
+/// `commands::ollama_generate_stream` but carrying the full conversation history.
+#[tauri::command]
+pub async fn ollama_chat_stream(
+    app: AppHandle,
+    window: Window,
+    model: String,
+    messages: Vec<ChatMessage>,
+    options: Option<GenerateOptions>,
+) -> Result<(), String> {
+    ensure_ollama_running(&app).await?;
+
+    let request_id = new_chat_request_id();
+    let base_url = ollama_base_url()?;
+
+    let mut body = json!({
+        "model": model,
+        "messages": messages,
+        "stream": true,
+    });
+    if let Some(options) = options {
+        body["options"] = json!(options);
+    }
+
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/chat", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut stream = resp.bytes_stream();
+    let mut buf = String::new();
+    'outer: while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        buf.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(pos) = buf.find('\n') {
+            let line = buf[..pos].trim().to_string();
+            buf.drain(..=pos);
+            if line.is_empty() {
+                continue;
+            }
+            let parsed: ChatChunk = serde_json::from_str(&line).map_err(|e| e.to_string())?;
+            let _ = window.emit(
+                "ollama-chat-token",
+                ChatTokenPayload {
+                    request_id: &request_id,
+                    token: &parsed.message.content,
+                },
+            );
+            if parsed.done {
+                break 'outer;
+            }
+        }
+    }
+
+    let _ = window.emit(
+        "ollama-chat-done",
+        ChatDonePayload {
+            request_id: &request_id,
+        },
+    );
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Window};
+
+use super::config::ollama_base_url;
+use super::detector::ensure_ollama_running;
+
+/// Set by `cancel_ollama_pull` and polled once per chunk in `ollama_pull_model`,
+/// mirroring the `CANCEL_DOWNLOAD` flag `audio_ai` uses to abort its downloads.
+static CANCEL_PULL: AtomicBool = AtomicBool::new(false);
+
+#[tauri::command]
+pub fn cancel_ollama_pull() {
+    CANCEL_PULL.store(true, Ordering::SeqCst);
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaModel {
+    pub name: String,
+    pub size: u64,
+    pub digest: String,
+    pub modified_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningModel {
+    pub name: String,
+    pub size: u64,
+    pub size_vram: u64,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateChunk {
+    response: String,
+    #[serde(default)]
+    done: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GenerateTokenPayload<'a> {
+    request_id: &'a str,
+    token: &'a str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GenerateDonePayload<'a> {
+    request_id: &'a str,
+}
+
+fn new_request_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("gen-{}", nanos)
+}
+
+#[tauri::command]
+pub async fn ollama_list_models() -> Result<Vec<OllamaModel>, String> {
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::get(format!("{}/api/tags", base_url))
+        .await
+        .map_err(|e| e.to_string())?;
+    let body: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
+    let models = body.get("models").cloned().unwrap_or_else(|| json!([]));
+    serde_json::from_value(models).map_err(|e| e.to_string())
+}
+
+/// GETs /api/ps to show which models are currently loaded in VRAM, so users can see
+/// memory use before kicking off another generate.
+#[tauri::command]
+pub async fn ollama_list_running() -> Result<Vec<RunningModel>, String> {
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::get(format!("{}/api/ps", base_url))
+        .await
+        .map_err(|e| e.to_string())?;
+    let body: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
+    let models = body.get("models").cloned().unwrap_or_else(|| json!([]));
+    serde_json::from_value(models).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn ollama_pull_model(window: Window, name: String) -> Result<(), String> {
+    CANCEL_PULL.store(false, Ordering::SeqCst);
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/pull", base_url))
+        .json(&json!({ "name": name, "stream": true }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut stream = resp.bytes_stream();
+    let mut buf = String::new();
+    while let Some(chunk) = stream.next().await {
+        if CANCEL_PULL.load(Ordering::SeqCst) {
+            let _ = window.emit("ollama-pull-progress", json!({ "status": "cancelled" }));
+            drop(stream);
+            return Ok(());
+        }
+
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        buf.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(pos) = buf.find('\n') {
+            let line = buf[..pos].trim().to_string();
+            buf.drain(..=pos);
+            if line.is_empty() {
+                continue;
+            }
+            let _ = window.emit("ollama-pull-progress", &line);
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn ollama_delete_model(name: String) -> Result<(), String> {
+    let base_url = ollama_base_url()?;
+    reqwest::Client::new()
+        .delete(format!("{}/api/delete", base_url))
+        .json(&json!({ "name": name }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Streams tokens from `/api/generate` as they arrive so the chat UI can render them
+/// as they're typed, instead of waiting for the whole response like `legacy::ollama_generate`.
+#[tauri::command]
+pub async fn ollama_generate_stream(
+    app: AppHandle,
+    window: Window,
+    model: String,
+    prompt: String,
+    system: Option<String>,
+    keep_alive: Option<String>,
+) -> Result<(), String> {
+    ensure_ollama_running(&app).await?;
+
+    let request_id = new_request_id();
+    let base_url = ollama_base_url()?;
+
+    let mut body = json!({
+        "model": model,
+        "prompt": prompt,
+        "stream": true,
+    });
+    if let Some(system) = system {
+        body["system"] = json!(system);
+    }
+    if let Some(keep_alive) = keep_alive {
+        body["keep_alive"] = json!(keep_alive);
+    }
+
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/generate", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut stream = resp.bytes_stream();
+    let mut buf = String::new();
+    'outer: while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        buf.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(pos) = buf.find('\n') {
+            let line = buf[..pos].trim().to_string();
+            buf.drain(..=pos);
+            if line.is_empty() {
+                continue;
+            }
+            let parsed: GenerateChunk = serde_json::from_str(&line).map_err(|e| e.to_string())?;
+            let _ = window.emit(
+                "ollama-generate-token",
+                GenerateTokenPayload {
+                    request_id: &request_id,
+                    token: &parsed.response,
+                },
+            );
+            if parsed.done {
+                break 'outer;
+            }
+        }
+    }
+
+    let _ = window.emit(
+        "ollama-generate-done",
+        GenerateDonePayload {
+            request_id: &request_id,
+        },
+    );
+    Ok(())
+}
+
+/// Sends an empty generate request just to load `model` into VRAM ahead of time, so
+/// the first real question doesn't pay the load latency. `keep_alive` controls how long
+/// Ollama keeps it resident afterward (e.g. "10m", or "-1" to keep it loaded forever).
+#[tauri::command]
+pub async fn warm_up_model(model: String, keep_alive: Option<String>) -> Result<(), String> {
+    let base_url = ollama_base_url()?;
+    let mut body = json!({
+        "model": model,
+        "prompt": "",
+        "stream": false,
+    });
+    if let Some(keep_alive) = keep_alive {
+        body["keep_alive"] = json!(keep_alive);
+    }
+
+    reqwest::Client::new()
+        .post(format!("{}/api/generate", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
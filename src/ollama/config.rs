@@ -0,0 +1,19 @@
+use reqwest::Url;
+
+const DEFAULT_BASE_URL: &str = "http://localhost:11434";
+
+/// Resolves the Ollama server URL: the `OLLAMA_HOST` env var wins if set, otherwise
+/// falls back to the default local address. Callers in `commands`, `detector`, and
+/// `installer` should route every request through this instead of hard-coding the host,
+/// since Ollama doesn't have to run on the same machine as the app.
+pub fn ollama_base_url() -> Result<String, String> {
+    let raw = std::env::var("OLLAMA_HOST").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+    let raw = if raw.contains("://") {
+        raw
+    } else {
+        format!("http://{}", raw)
+    };
+
+    let url = Url::parse(&raw).map_err(|e| format!("invalid OLLAMA_HOST '{}': {}", raw, e))?;
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::database::Database;
+
+use super::config::ollama_base_url;
+
+/// Pings the local Ollama server to decide whether it's installed and running.
+/// Used to gate the install flow so we don't re-download onto a working setup.
+#[tauri::command]
+pub async fn check_ollama_installed() -> bool {
+    let base_url = match ollama_base_url() {
+        Ok(url) => url,
+        Err(_) => return false,
+    };
+    reqwest::get(format!("{}/api/version", base_url))
+        .await
+        .map(|resp| resp.status().is_success())
+        .unwrap_or(false)
+}
+
+/// Same reachability check as `check_ollama_installed`, named for call sites that only
+/// care whether the server will answer a request right now (vs. whether it's present
+/// on the machine at all, which `ensure_ollama_running` checks separately via the
+/// `ollama` binary before trying to start it).
+#[tauri::command]
+pub async fn check_ollama_running() -> bool {
+    check_ollama_installed().await
+}
+
+fn ollama_binary_exists() -> bool {
+    std::process::Command::new("ollama")
+        .arg("--version")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Spawns `ollama serve` detached, leaving it running in the background. Callers poll
+/// `check_ollama_running` afterward instead of this function blocking on startup.
+#[tauri::command]
+pub fn start_ollama_service() -> Result<(), String> {
+    std::process::Command::new("ollama")
+        .arg("serve")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+const RUNNING_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const RUNNING_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn auto_start_enabled(app: &AppHandle) -> bool {
+    crate::database::get_setting(app.state::<Database>(), "auto_start_ollama".to_string())
+        .ok()
+        .flatten()
+        .map(|setting| setting.value == "true")
+        .unwrap_or(false)
+}
+
+/// Makes sure Ollama will answer before a generate/chat call proceeds. A no-op if it's
+/// already running. If it's not running but `auto_start_ollama` is enabled and the
+/// `ollama` binary is on PATH, starts it and polls for up to 10s. Otherwise returns a
+/// clear error instead of letting the HTTP call fail with connection-refused deep
+/// inside reqwest.
+pub async fn ensure_ollama_running(app: &AppHandle) -> Result<(), String> {
+    if check_ollama_running().await {
+        return Ok(());
+    }
+
+    if !auto_start_enabled(app) {
+        return Err("Ollama isn't running (enable auto_start_ollama to start it automatically)".to_string());
+    }
+
+    if !ollama_binary_exists() {
+        crate::logger::log("ollama: auto-start enabled but the ollama binary isn't on PATH");
+        return Err("Ollama isn't running and doesn't appear to be installed".to_string());
+    }
+
+    start_ollama_service()?;
+
+    let deadline = Instant::now() + RUNNING_POLL_TIMEOUT;
+    while Instant::now() < deadline {
+        if check_ollama_running().await {
+            return Ok(());
+        }
+        tokio::time::sleep(RUNNING_POLL_INTERVAL).await;
+    }
+
+    crate::logger::log("ollama: timed out waiting for the auto-started service to come up");
+    Err("timed out waiting for Ollama to start".to_string())
+}
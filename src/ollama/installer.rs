@@ -0,0 +1,97 @@
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tauri::Window;
+
+const OLLAMA_WINDOWS_INSTALLER_URL: &str = "https://ollama.com/download/OllamaSetup.exe";
+const INSTALL_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const INSTALL_PROGRESS_CAP: u32 = 90;
+
+/// PE executables start with this two-byte "MZ" magic; a truncated or corrupted
+/// download won't have it even if the byte count happens to look plausible.
+const PE_MAGIC: &[u8] = b"MZ";
+
+/// Downloads the Windows installer to a temp file and returns its path. Verifies the
+/// download against the response's `content-length` (when present) and the PE magic
+/// bytes so a truncated download fails loudly instead of silently "installing" later.
+#[tauri::command]
+pub async fn download_ollama(window: Window) -> Result<PathBuf, String> {
+    let resp = reqwest::get(OLLAMA_WINDOWS_INSTALLER_URL)
+        .await
+        .map_err(|e| e.to_string())?;
+    let expected_len = resp.content_length();
+    let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+
+    if let Some(expected_len) = expected_len {
+        if bytes.len() as u64 != expected_len {
+            return Err(format!(
+                "download truncated: expected {} bytes, got {}",
+                expected_len,
+                bytes.len()
+            ));
+        }
+    }
+    if bytes.is_empty() || !bytes.starts_with(PE_MAGIC) {
+        return Err("downloaded file is not a valid Windows executable".to_string());
+    }
+
+    let dest = std::env::temp_dir().join("OllamaSetup.exe");
+    if let Err(e) = std::fs::write(&dest, &bytes) {
+        let _ = std::fs::remove_file(&dest);
+        return Err(e.to_string());
+    }
+    let _ = window.emit("ollama-download-progress", bytes.len());
+    Ok(dest)
+}
+
+/// Runs the downloaded installer silently, emitting `ollama-install-progress` updates
+/// while it runs instead of blocking on `.output()` with no feedback. Progress creeps
+/// toward 90% while the child is alive, then jumps to 100% once `check_ollama_installed`
+/// confirms the install actually worked.
+#[tauri::command]
+pub async fn install_ollama_windows(window: Window, installer_path: PathBuf) -> Result<(), String> {
+    let mut child = std::process::Command::new(&installer_path)
+        .arg("/S")
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let mut progress: u32 = 0;
+    loop {
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(status) => {
+                if !status.success() {
+                    let mut stderr_text = String::new();
+                    if let Some(mut stderr) = child.stderr.take() {
+                        let _ = stderr.read_to_string(&mut stderr_text);
+                    }
+                    return Err(format!(
+                        "installer exited with {:?}: {}",
+                        status.code(),
+                        stderr_text.trim()
+                    ));
+                }
+                break;
+            }
+            None => {
+                progress = (progress + 5).min(INSTALL_PROGRESS_CAP);
+                let _ = window.emit("ollama-install-progress", progress);
+                tokio::time::sleep(INSTALL_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    if !super::detector::check_ollama_installed().await {
+        return Err("installer finished but Ollama is not reachable".to_string());
+    }
+    let _ = window.emit("ollama-install-progress", 100);
+    Ok(())
+}
+
+/// Downloads and installs Ollama in one step, for the "one click setup" button.
+#[tauri::command]
+pub async fn install_ollama_one_click(window: Window) -> Result<(), String> {
+    let installer_path = download_ollama(window.clone()).await?;
+    install_ollama_windows(window, installer_path).await
+}
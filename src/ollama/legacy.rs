@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::AppHandle;
+
+use super::config::ollama_base_url;
+use super::detector::ensure_ollama_running;
+
+#[derive(Debug, Deserialize)]
+struct GenerateResponse {
+    response: String,
+}
+
+/// Sampling knobs forwarded to Ollama's `options` object. Every field is optional and
+/// omitted from the request when unset, so Ollama falls back to its own defaults
+/// instead of us guessing values for fields the caller didn't ask to override.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GenerateOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_ctx: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_predict: Option<i32>,
+}
+
+/// Non-streaming generate, kept around for callers that just want the final text
+/// (e.g. one-shot prompts) without wiring up token events. Prefer
+/// `commands::ollama_generate_stream` for anything user-facing.
+#[tauri::command]
+pub async fn ollama_generate(
+    app: AppHandle,
+    model: String,
+    prompt: String,
+    system: Option<String>,
+    options: Option<GenerateOptions>,
+    keep_alive: Option<String>,
+) -> Result<String, String> {
+    ensure_ollama_running(&app).await?;
+
+    let mut body = json!({
+        "model": model,
+        "prompt": prompt,
+        "stream": false,
+    });
+    if let Some(system) = system {
+        body["system"] = json!(system);
+    }
+    if let Some(options) = options {
+        body["options"] = json!(options);
+    }
+    if let Some(keep_alive) = keep_alive {
+        body["keep_alive"] = json!(keep_alive);
+    }
+
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/generate", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let parsed: GenerateResponse = resp.json().await.map_err(|e| e.to_string())?;
+    Ok(parsed.response)
+}
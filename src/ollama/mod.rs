@@ -0,0 +1,10 @@
+pub mod chat;
+pub mod commands;
+pub mod config;
+pub mod detector;
+pub mod installer;
+pub mod legacy;
+pub mod recommend;
+pub mod vision;
+
+pub use config::ollama_base_url;
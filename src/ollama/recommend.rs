@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use serde_json::json;
+use sysinfo::System;
+
+use super::commands::OllamaModel;
+use super::config::ollama_base_url;
+
+#[derive(Debug, Deserialize)]
+struct ShowResponse {
+    details: ShowDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShowDetails {
+    parameter_size: Option<String>,
+}
+
+/// Parses a `parameter_size` string like "7B" or "13.4B" (as returned by `/api/show`)
+/// into an estimated memory footprint, assuming ~0.75 bytes per parameter — roughly
+/// right for the Q4-ish quantizations most Ollama models ship with by default.
+fn estimate_bytes(parameter_size: &str) -> Option<u64> {
+    let trimmed = parameter_size.trim().trim_end_matches(|c: char| c.is_alphabetic());
+    let billions: f64 = trimmed.parse().ok()?;
+    Some((billions * 1_000_000_000.0 * 0.75) as u64)
+}
+
+/// Picks the largest installed model whose estimated memory footprint comfortably fits
+/// in available system RAM, so a low-RAM machine doesn't reach for a model that's going
+/// to fail with an out-of-memory error. `task` isn't used to distinguish model
+/// suitability yet — every installed model is a general-purpose candidate today — but
+/// keeps the door open for per-task preferences later.
+#[tauri::command]
+pub async fn recommend_model(task: String) -> Result<String, String> {
+    let _ = task;
+
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::get(format!("{}/api/tags", base_url))
+        .await
+        .map_err(|e| e.to_string())?;
+    let body: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
+    let models: Vec<OllamaModel> =
+        serde_json::from_value(body.get("models").cloned().unwrap_or_else(|| json!([]))).map_err(|e| e.to_string())?;
+
+    if models.is_empty() {
+        return Err("no models installed".to_string());
+    }
+
+    let mut system = System::new_all();
+    system.refresh_memory();
+    // Leave headroom so the model doesn't starve the rest of the OS.
+    let budget = (system.available_memory() as f64 * 0.8) as u64;
+
+    let client = reqwest::Client::new();
+    let mut best: Option<(String, u64)> = None;
+    for model in models {
+        let resp = client
+            .post(format!("{}/api/show", base_url))
+            .json(&json!({ "name": model.name }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let show: ShowResponse = match resp.json().await {
+            Ok(show) => show,
+            Err(_) => continue,
+        };
+        let Some(parameter_size) = show.details.parameter_size else {
+            continue;
+        };
+        let Some(estimated) = estimate_bytes(&parameter_size) else {
+            continue;
+        };
+        if estimated > budget {
+            continue;
+        }
+        if best.as_ref().map_or(true, |(_, size)| estimated > *size) {
+            best = Some((model.name, estimated));
+        }
+    }
+
+    best.map(|(name, _)| name)
+        .ok_or_else(|| "no installed model fits in available memory".to_string())
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::config::ollama_base_url;
+
+#[derive(Debug, Deserialize)]
+struct GenerateResponse {
+    response: String,
+}
+
+/// Strips a `data:image/png;base64,` (or similar) prefix if present, since captures
+/// are sometimes passed around as data URLs but Ollama expects bare base64.
+fn strip_data_url_prefix(image: &str) -> &str {
+    match image.find("base64,") {
+        Some(idx) => &image[idx + "base64,".len()..],
+        None => image,
+    }
+}
+
+/// Sends one or more base64 images to a vision model (e.g. llava) alongside a text
+/// prompt, giving a cross-platform OCR/description path that doesn't depend on
+/// Windows.Media.Ocr.
+#[tauri::command]
+pub async fn ollama_generate_vision(
+    model: String,
+    prompt: String,
+    images: Vec<String>,
+) -> Result<String, String> {
+    let images: Vec<&str> = images.iter().map(|img| strip_data_url_prefix(img)).collect();
+
+    let body = json!({
+        "model": model,
+        "prompt": prompt,
+        "images": images,
+        "stream": false,
+    });
+
+    let base_url = ollama_base_url()?;
+    let resp = reqwest::Client::new()
+        .post(format!("{}/api/generate", base_url))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let parsed: GenerateResponse = resp.json().await.map_err(|e| e.to_string())?;
+    Ok(parsed.response)
+}
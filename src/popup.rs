@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State, WindowBuilder, WindowUrl};
+use xcap::Monitor;
+
+use crate::database::Database;
+use crate::ocr::OcrResult;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PendingData {
+    pub result: Option<OcrResult>,
+    pub progress: Option<f64>,
+}
+
+#[derive(Default)]
+pub struct PopupState {
+    pending: Mutex<HashMap<String, PendingData>>,
+    /// Per-label generation counter backing the auto-close timer. A spawned timer
+    /// captures the generation it was scheduled with; `keep_popup_open` bumps the
+    /// counter so the timer's eventual check-and-close becomes a no-op instead of
+    /// needing to abort the sleeping task outright.
+    timers: Mutex<HashMap<String, Arc<AtomicU64>>>,
+}
+
+fn popup_label(id: &str) -> String {
+    format!("ocr-popup-{}", id)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PopupCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+impl PopupCorner {
+    fn as_str(self) -> &'static str {
+        match self {
+            PopupCorner::TopLeft => "top_left",
+            PopupCorner::TopRight => "top_right",
+            PopupCorner::BottomLeft => "bottom_left",
+            PopupCorner::BottomRight => "bottom_right",
+            PopupCorner::Center => "center",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "top_left" => Some(PopupCorner::TopLeft),
+            "top_right" => Some(PopupCorner::TopRight),
+            "bottom_left" => Some(PopupCorner::BottomLeft),
+            "bottom_right" => Some(PopupCorner::BottomRight),
+            "center" => Some(PopupCorner::Center),
+            _ => None,
+        }
+    }
+}
+
+const POPUP_CORNER_SETTING: &str = "popup_corner";
+const POPUP_MARGIN: i32 = 16;
+const POPUP_WIDTH: f64 = 360.0;
+const POPUP_HEIGHT: f64 = 200.0;
+
+/// Resolves the corner to place a new popup in: an explicit `position` wins, otherwise
+/// the last corner a caller picked (persisted via the settings table), falling back to
+/// bottom-right if neither is set.
+fn resolve_corner(app: &AppHandle, position: Option<PopupCorner>) -> PopupCorner {
+    if let Some(corner) = position {
+        let _ = crate::database::set_setting(
+            app.state::<Database>(),
+            POPUP_CORNER_SETTING.to_string(),
+            corner.as_str().to_string(),
+            "string".to_string(),
+            "popup".to_string(),
+        );
+        return corner;
+    }
+
+    crate::database::get_setting(app.state::<Database>(), POPUP_CORNER_SETTING.to_string())
+        .ok()
+        .flatten()
+        .and_then(|setting| PopupCorner::parse(&setting.value))
+        .unwrap_or(PopupCorner::BottomRight)
+}
+
+/// Computes the top-left (x, y) to place a `POPUP_WIDTH`x`POPUP_HEIGHT` popup at the
+/// given corner of `monitor`, in physical pixels, clamped so it never lands off-screen
+/// even on a monitor smaller than the popup plus margin.
+fn compute_popup_position(monitor: &Monitor, corner: PopupCorner) -> (i32, i32) {
+    let scale = monitor.scale_factor() as f64;
+    let margin = (POPUP_MARGIN as f64 * scale).round() as i32;
+    let width = (POPUP_WIDTH * scale).round() as i32;
+    let height = (POPUP_HEIGHT * scale).round() as i32;
+
+    let mon_x = monitor.x();
+    let mon_y = monitor.y();
+    let mon_w = monitor.width() as i32;
+    let mon_h = monitor.height() as i32;
+
+    let (x, y) = match corner {
+        PopupCorner::TopLeft => (mon_x + margin, mon_y + margin),
+        PopupCorner::TopRight => (mon_x + mon_w - width - margin, mon_y + margin),
+        PopupCorner::BottomLeft => (mon_x + margin, mon_y + mon_h - height - margin),
+        PopupCorner::BottomRight => (mon_x + mon_w - width - margin, mon_y + mon_h - height - margin),
+        PopupCorner::Center => (mon_x + (mon_w - width) / 2, mon_y + (mon_h - height) / 2),
+    };
+
+    let max_x = (mon_x + mon_w - width).max(mon_x);
+    let max_y = (mon_y + mon_h - height).max(mon_y);
+    (x.clamp(mon_x, max_x), y.clamp(mon_y, max_y))
+}
+
+/// Bumps (or creates) `label`'s timer generation and returns the new value, invalidating
+/// any in-flight auto-close timer scheduled under an older generation.
+fn bump_timer_generation(state: &PopupState, label: &str) -> u64 {
+    let mut timers = state.timers.lock().unwrap();
+    let counter = timers
+        .entry(label.to_string())
+        .or_insert_with(|| Arc::new(AtomicU64::new(0)));
+    counter.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Spawns a timer that closes `label` after `delay_ms` unless its generation has moved
+/// on in the meantime (via `keep_popup_open` or `close_popup` running first).
+fn schedule_auto_close(app: AppHandle, label: String, delay_ms: u64) {
+    let generation = bump_timer_generation(&app.state::<PopupState>(), &label);
+    let counter = app
+        .state::<PopupState>()
+        .timers
+        .lock()
+        .unwrap()
+        .get(&label)
+        .unwrap()
+        .clone();
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        if counter.load(Ordering::SeqCst) == generation {
+            if let Some(window) = app.get_window(&label) {
+                let _ = window.close();
+            }
+            app.state::<PopupState>().pending.lock().unwrap().remove(&label);
+        }
+    });
+}
+
+/// Creates (or reuses) the OCR popup window for `id`. The result is buffered in
+/// PopupState under the window's label rather than delivered with `window.eval` once
+/// the window happens to exist — the popup calls `get_popup_data` itself once its
+/// webview has mounted, so there's no race between window creation and page load and
+/// no hard-coded sleep waiting for "probably loaded by now".
+///
+/// `position` places a newly created popup at a screen corner (on the first monitor
+/// `Monitor::all` lists);
+/// omitting it reuses whichever corner was last picked, persisted across launches.
+/// `auto_close_ms`, when set, closes the popup after that delay unless the frontend
+/// calls `keep_popup_open` first (e.g. on hover).
+#[tauri::command]
+pub fn create_ocr_popup(
+    app: AppHandle,
+    id: String,
+    result: OcrResult,
+    position: Option<PopupCorner>,
+    auto_close_ms: Option<u64>,
+) -> Result<(), String> {
+    let label = popup_label(&id);
+    app.state::<PopupState>().pending.lock().unwrap().insert(
+        label.clone(),
+        PendingData {
+            result: Some(result),
+            progress: None,
+        },
+    );
+
+    if app.get_window(&label).is_none() {
+        let corner = resolve_corner(&app, position);
+        let mut builder = WindowBuilder::new(&app, &label, WindowUrl::App("popup.html".into()))
+            .always_on_top(true)
+            .decorations(false)
+            .inner_size(POPUP_WIDTH, POPUP_HEIGHT);
+
+        if let Ok(monitors) = Monitor::all() {
+            if let Some(monitor) = monitors.first() {
+                let (x, y) = compute_popup_position(monitor, corner);
+                builder = builder.position(x as f64, y as f64);
+            }
+        }
+
+        builder.build().map_err(|e| e.to_string())?;
+    } else {
+        let _ = app.emit_all(&format!("popup-data-ready-{}", label), ());
+    }
+
+    if let Some(delay_ms) = auto_close_ms {
+        schedule_auto_close(app, label, delay_ms);
+    }
+
+    Ok(())
+}
+
+/// Cancels `label`'s pending auto-close timer, called by the popup on hover so it
+/// doesn't vanish out from under a user who's actively reading it.
+#[tauri::command]
+pub fn keep_popup_open(state: State<PopupState>, label: String) {
+    bump_timer_generation(&state, &label);
+}
+
+/// Closes a single popup immediately and cancels any pending auto-close timer for it.
+#[tauri::command]
+pub fn close_popup(app: AppHandle, label: String) -> Result<(), String> {
+    bump_timer_generation(&app.state::<PopupState>(), &label);
+    app.state::<PopupState>().pending.lock().unwrap().remove(&label);
+    if let Some(window) = app.get_window(&label) {
+        window.close().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Replaces a popup's buffered data (e.g. a progress update ahead of the final
+/// result) and nudges an already-open popup to re-fetch it.
+#[tauri::command]
+pub fn update_ocr_popup(app: AppHandle, id: String, result: Option<OcrResult>, progress: Option<f64>) -> Result<(), String> {
+    let label = popup_label(&id);
+    app.state::<PopupState>()
+        .pending
+        .lock()
+        .unwrap()
+        .insert(label.clone(), PendingData { result, progress });
+    let _ = app.emit_all(&format!("popup-data-ready-{}", label), ());
+    Ok(())
+}
+
+/// Called by the popup webview once it's mounted and has attached its
+/// `popup-data-ready-<label>` listener, replacing the old 1s sleep + eval fallback. Returns whatever's currently
+/// buffered for `label` — possibly nothing yet if the caller hasn't set it.
+#[tauri::command]
+pub fn get_popup_data(state: State<PopupState>, label: String) -> Option<PendingData> {
+    state.pending.lock().unwrap().get(&label).cloned()
+}
+
+/// Closes every open OCR popup window in one call, for a "dismiss all" action. Also
+/// closes the screenshot overlay when `close_overlay` is true, since a stray overlay
+/// left open alongside a pile of popups is the same kind of clutter. Returns how many
+/// popup windows were closed (the overlay, if any, isn't counted).
+#[tauri::command]
+pub fn close_all_popups(app: AppHandle, close_overlay: Option<bool>) -> Result<usize, String> {
+    let mut closed = 0;
+    for window in app.windows().values() {
+        let label = window.label();
+        if label.starts_with("ocr-popup-") {
+            bump_timer_generation(&app.state::<PopupState>(), label);
+            app.state::<PopupState>().pending.lock().unwrap().remove(label);
+            window.close().map_err(|e| e.to_string())?;
+            closed += 1;
+        } else if close_overlay.unwrap_or(false) && label == "screenshot_overlay" {
+            window.close().map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(closed)
+}
@@ -0,0 +1,361 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::{DynamicImage, GenericImageView};
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+use xcap::Monitor;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenshotRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenshotResult {
+    pub success: bool,
+    pub image_data: Option<String>,
+    pub error: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub byte_size: Option<usize>,
+    /// Set when `save_to` was provided and the image was written straight to disk.
+    pub image_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorInfo {
+    pub index: usize,
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f32,
+}
+
+#[tauri::command]
+pub fn list_monitors() -> Result<Vec<MonitorInfo>, String> {
+    let monitors = Monitor::all().map_err(|e| e.to_string())?;
+    Ok(monitors
+        .iter()
+        .enumerate()
+        .map(|(index, m)| MonitorInfo {
+            index,
+            name: m.name().to_string(),
+            x: m.x(),
+            y: m.y(),
+            width: m.width(),
+            height: m.height(),
+            scale_factor: m.scale_factor(),
+        })
+        .collect())
+}
+
+fn capture_screen_internal(
+    region: Option<ScreenshotRegion>,
+    monitor_index: Option<usize>,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_to: Option<String>,
+) -> Result<ScreenshotResult, String> {
+    let monitors = Monitor::all().map_err(|e| {
+        crate::logger::log(&format!("screenshot: failed to list monitors: {}", e));
+        e.to_string()
+    })?;
+    let index = monitor_index.unwrap_or(0);
+    let monitor = monitors
+        .get(index)
+        .ok_or_else(|| format!("no monitor at index {}", index))?;
+
+    let image = monitor.capture_image().map_err(|e| {
+        crate::logger::log(&format!("screenshot: failed to capture monitor {}: {}", index, e));
+        e.to_string()
+    })?;
+    let dynamic: DynamicImage = DynamicImage::ImageRgba8(image);
+
+    let cropped = match region {
+        Some(r) => {
+            let scale = monitor.scale_factor();
+            let (cx, cy, cw, ch) = clamp_region(
+                (r.x as f32 * scale) as i32,
+                (r.y as f32 * scale) as i32,
+                (r.width as f32 * scale) as u32,
+                (r.height as f32 * scale) as u32,
+                dynamic.width(),
+                dynamic.height(),
+            );
+            dynamic.crop_imm(cx, cy, cw, ch)
+        }
+        None => dynamic,
+    };
+
+    let width = cropped.width();
+    let height = cropped.height();
+
+    let format = format.unwrap_or_else(|| "png".to_string()).to_lowercase();
+    let (image_format, mime) = match format.as_str() {
+        "png" => (image::ImageFormat::Png, "image/png"),
+        "jpeg" | "jpg" => (image::ImageFormat::Jpeg, "image/jpeg"),
+        "webp" => (image::ImageFormat::WebP, "image/webp"),
+        other => return Err(format!("unsupported screenshot format: {}", other)),
+    };
+
+    let mut bytes: Vec<u8> = Vec::new();
+    if image_format == image::ImageFormat::Jpeg {
+        let quality = quality.unwrap_or(85);
+        let mut encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality);
+        encoder
+            .encode_image(&cropped)
+            .map_err(|e| e.to_string())?;
+    } else {
+        cropped
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image_format)
+            .map_err(|e| e.to_string())?;
+    }
+    let byte_size = bytes.len();
+
+    let image_path = match save_to {
+        Some(path) => {
+            let path = std::path::Path::new(&path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            std::fs::write(path, &bytes).map_err(|e| e.to_string())?;
+            Some(path.to_string_lossy().into_owned())
+        }
+        None => None,
+    };
+
+    // Skip the base64 round-trip when the caller only wanted the file on disk.
+    let image_data = if image_path.is_some() {
+        None
+    } else {
+        Some(format!("data:{};base64,{}", mime, STANDARD.encode(bytes)))
+    };
+
+    Ok(ScreenshotResult {
+        success: true,
+        image_data,
+        error: None,
+        width: Some(width),
+        height: Some(height),
+        byte_size: Some(byte_size),
+        image_path,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorResult {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub hex: String,
+}
+
+/// Reads the pixel color at a global screen coordinate, for an eyedropper tool.
+/// `x`/`y` are logical coordinates (as reported by the cursor), so they're scaled up
+/// to the monitor's physical pixels before cropping a single-pixel region.
+#[tauri::command]
+pub fn pick_color_at(x: i32, y: i32) -> Result<ColorResult, String> {
+    let monitors = Monitor::all().map_err(|e| e.to_string())?;
+    let monitor = monitors
+        .iter()
+        .find(|m| x >= m.x() && x < m.x() + m.width() as i32 && y >= m.y() && y < m.y() + m.height() as i32)
+        .ok_or_else(|| "no monitor contains that coordinate".to_string())?;
+
+    let image = monitor.capture_image().map_err(|e| e.to_string())?;
+    let dynamic: DynamicImage = DynamicImage::ImageRgba8(image);
+
+    let scale = monitor.scale_factor();
+    let local_x = ((x - monitor.x()) as f32 * scale) as i32;
+    let local_y = ((y - monitor.y()) as f32 * scale) as i32;
+    let (px, py, _, _) = clamp_region(local_x, local_y, 1, 1, dynamic.width(), dynamic.height());
+
+    let pixel = dynamic.crop_imm(px, py, 1, 1).get_pixel(0, 0);
+    let [r, g, b, _] = pixel.0;
+
+    Ok(ColorResult {
+        r,
+        g,
+        b,
+        hex: format!("#{:02x}{:02x}{:02x}", r, g, b),
+    })
+}
+
+fn clamp_region(x: i32, y: i32, width: u32, height: u32, img_width: u32, img_height: u32) -> (u32, u32, u32, u32) {
+    let x = x.max(0).min(img_width as i32) as u32;
+    let y = y.max(0).min(img_height as i32) as u32;
+    let width = width.min(img_width.saturating_sub(x));
+    let height = height.min(img_height.saturating_sub(y));
+    (x, y, width, height)
+}
+
+/// Captures the active window's bounds on Windows via `GetForegroundWindow`/`GetWindowRect`.
+/// Other platforms fall back to a fullscreen capture since xcap has no window-level capture.
+#[tauri::command]
+pub fn capture_window() -> Result<ScreenshotResult, String> {
+    #[cfg(target_os = "windows")]
+    {
+        if let Some((x, y, width, height)) = crate::context::get_foreground_window_rect() {
+            if width > 0 && height > 0 {
+                let monitors = Monitor::all().map_err(|e| e.to_string())?;
+                let (monitor_index, origin_x, origin_y) = monitors
+                    .iter()
+                    .enumerate()
+                    .find(|(_, m)| x >= m.x() && x < m.x() + m.width() as i32 && y >= m.y() && y < m.y() + m.height() as i32)
+                    .map(|(i, m)| (i, m.x(), m.y()))
+                    .unwrap_or((0, 0, 0));
+                let region = ScreenshotRegion {
+                    x: x - origin_x,
+                    y: y - origin_y,
+                    width: width as u32,
+                    height: height as u32,
+                };
+                return capture_screen_internal(Some(region), Some(monitor_index), None, None, None);
+            }
+        }
+    }
+
+    capture_screen_internal(None, None, None, None, None)
+}
+
+#[tauri::command]
+pub fn capture_fullscreen(
+    monitor_index: Option<usize>,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_to: Option<String>,
+) -> Result<ScreenshotResult, String> {
+    capture_screen_internal(None, monitor_index, format, quality, save_to)
+}
+
+#[tauri::command]
+pub fn capture_region(
+    region: ScreenshotRegion,
+    monitor_index: Option<usize>,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_to: Option<String>,
+) -> Result<ScreenshotResult, String> {
+    capture_screen_internal(Some(region), monitor_index, format, quality, save_to)
+}
+
+const MAX_CAPTURE_DELAY_MS: u64 = 10_000;
+
+async fn countdown(app: &tauri::AppHandle, delay_ms: u64) {
+    let delay_ms = delay_ms.min(MAX_CAPTURE_DELAY_MS);
+    let mut remaining = (delay_ms + 999) / 1000;
+    while remaining > 0 {
+        let _ = app.emit_all("capture-countdown", remaining);
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        remaining -= 1;
+    }
+}
+
+#[tauri::command]
+pub async fn capture_fullscreen_delayed(
+    app: tauri::AppHandle,
+    delay_ms: u64,
+    monitor_index: Option<usize>,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_to: Option<String>,
+) -> Result<ScreenshotResult, String> {
+    countdown(&app, delay_ms).await;
+    capture_screen_internal(None, monitor_index, format, quality, save_to)
+}
+
+#[tauri::command]
+pub async fn capture_region_delayed(
+    app: tauri::AppHandle,
+    delay_ms: u64,
+    region: ScreenshotRegion,
+    monitor_index: Option<usize>,
+    format: Option<String>,
+    quality: Option<u8>,
+    save_to: Option<String>,
+) -> Result<ScreenshotResult, String> {
+    countdown(&app, delay_ms).await;
+    capture_screen_internal(Some(region), monitor_index, format, quality, save_to)
+}
+
+#[tauri::command]
+pub fn show_screenshot_overlay(app: tauri::AppHandle) -> Result<(), String> {
+    if app.get_window("screenshot_overlay").is_some() {
+        return Ok(());
+    }
+    tauri::WindowBuilder::new(&app, "screenshot_overlay", tauri::WindowUrl::App("overlay.html".into()))
+        .fullscreen(true)
+        .transparent(true)
+        .decorations(false)
+        .always_on_top(true)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn close_screenshot_overlay(app: tauri::AppHandle) -> Result<(), String> {
+    if let Some(overlay) = app.get_window("screenshot_overlay") {
+        overlay.close().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn capture_selected_region(app: tauri::AppHandle, region: ScreenshotRegion) -> Result<ScreenshotResult, String> {
+    if let Some(overlay) = app.get_window("screenshot_overlay") {
+        let _ = overlay.close();
+    }
+
+    if region.width == 0 || region.height == 0 {
+        return Ok(ScreenshotResult {
+            success: false,
+            image_data: None,
+            error: Some("cancelled".to_string()),
+            width: None,
+            height: None,
+            byte_size: None,
+            image_path: None,
+        });
+    }
+
+    let monitors = Monitor::all().map_err(|e| e.to_string())?;
+    let (monitor_index, origin_x, origin_y) = monitors
+        .iter()
+        .enumerate()
+        .find(|(_, m)| {
+            region.x >= m.x()
+                && region.x < m.x() + m.width() as i32
+                && region.y >= m.y()
+                && region.y < m.y() + m.height() as i32
+        })
+        .map(|(i, m)| (i, m.x(), m.y()))
+        .ok_or_else(|| "selected region is not on any monitor".to_string())?;
+
+    let local_region = ScreenshotRegion {
+        x: region.x - origin_x,
+        y: region.y - origin_y,
+        width: region.width,
+        height: region.height,
+    };
+
+    capture_screen_internal(Some(local_region), Some(monitor_index), None, None, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_region;
+
+    #[test]
+    fn clamps_region_near_right_edge() {
+        let (x, y, width, height) = clamp_region(1900, 100, 300, 300, 1920, 1080);
+        assert_eq!(x, 1900);
+        assert_eq!(y, 100);
+        assert_eq!(width, 20);
+        assert_eq!(height, 300);
+        assert!(width > 0 && height > 0);
+    }
+}
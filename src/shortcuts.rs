@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, GlobalShortcutManager, Manager, State};
+
+use crate::database::Database;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutConfig {
+    pub id: String,
+    pub accelerator: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+#[derive(Default)]
+pub struct ShortcutState {
+    pub shortcuts: Mutex<HashMap<String, ShortcutConfig>>,
+}
+
+fn emit_on_trigger(app: &AppHandle, shortcut_id: &str) -> impl Fn() {
+    let app = app.clone();
+    let shortcut_id = shortcut_id.to_string();
+    move || {
+        let _ = app.emit_all("shortcut-triggered", shortcut_id.clone());
+    }
+}
+
+/// Registers `accelerator` at the OS level and stores the config in state, so
+/// get_registered_shortcuts, update_shortcut, and set_shortcut_enabled can find it again.
+#[tauri::command]
+pub fn register_shortcut(
+    app: AppHandle,
+    shortcut_id: String,
+    accelerator: String,
+    description: String,
+) -> Result<(), String> {
+    app.global_shortcut_manager()
+        .register(&accelerator, emit_on_trigger(&app, &shortcut_id))
+        .map_err(|e| e.to_string())?;
+
+    let state = app.state::<ShortcutState>();
+    state.shortcuts.lock().unwrap().insert(
+        shortcut_id.clone(),
+        ShortcutConfig {
+            id: shortcut_id,
+            accelerator,
+            description,
+            enabled: true,
+        },
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_registered_shortcuts(state: State<ShortcutState>) -> Vec<ShortcutConfig> {
+    state.shortcuts.lock().unwrap().values().cloned().collect()
+}
+
+#[tauri::command]
+pub fn unregister_shortcut(app: AppHandle, shortcut_id: String) -> Result<(), String> {
+    let state = app.state::<ShortcutState>();
+    let config = state.shortcuts.lock().unwrap().remove(&shortcut_id);
+    if let Some(config) = config {
+        if config.enabled {
+            app.global_shortcut_manager()
+                .unregister(&config.accelerator)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Updates a shortcut's accelerator, preserving its existing description instead of
+/// overwriting it with a placeholder. If the new accelerator fails to register, the old
+/// one is re-registered so the user isn't left with no shortcut at all.
+#[tauri::command]
+pub fn update_shortcut(app: AppHandle, shortcut_id: String, accelerator: String) -> Result<(), String> {
+    let state = app.state::<ShortcutState>();
+    let existing = state
+        .shortcuts
+        .lock()
+        .unwrap()
+        .get(&shortcut_id)
+        .cloned()
+        .ok_or_else(|| format!("no shortcut registered with id {}", shortcut_id))?;
+
+    let mut manager = app.global_shortcut_manager();
+    manager.unregister(&existing.accelerator).map_err(|e| e.to_string())?;
+
+    match manager.register(&accelerator, emit_on_trigger(&app, &shortcut_id)) {
+        Ok(()) => {
+            state.shortcuts.lock().unwrap().insert(
+                shortcut_id.clone(),
+                ShortcutConfig {
+                    id: shortcut_id,
+                    accelerator,
+                    description: existing.description,
+                    enabled: existing.enabled,
+                },
+            );
+            Ok(())
+        }
+        Err(e) => {
+            let _ = manager.register(&existing.accelerator, emit_on_trigger(&app, &shortcut_id));
+            Err(e.to_string())
+        }
+    }
+}
+
+const DEFAULT_SHORTCUTS: &[(&str, &str, &str)] = &[
+    ("capture", "CommandOrControl+Shift+S", "Capture screenshot"),
+    ("history", "CommandOrControl+Shift+H", "Open capture history"),
+];
+
+const DEFAULTS_REGISTERED_SETTING: &str = "default_shortcuts_registered";
+
+/// Registers the default capture/history shortcuts on first launch, guarded by a
+/// settings flag so re-running setup doesn't double-register or clobber a user's own
+/// rebinding. Logs rather than fails on a conflict so one bad accelerator (e.g. already
+/// claimed by the OS or another app) doesn't stop the rest from registering.
+pub fn register_default_shortcuts(app: &AppHandle) {
+    let already_registered = crate::database::get_setting(
+        app.state::<Database>(),
+        DEFAULTS_REGISTERED_SETTING.to_string(),
+    )
+    .ok()
+    .flatten()
+    .is_some();
+    if already_registered {
+        return;
+    }
+
+    for (id, accelerator, description) in DEFAULT_SHORTCUTS {
+        if let Err(e) = register_shortcut(app.clone(), id.to_string(), accelerator.to_string(), description.to_string()) {
+            eprintln!("failed to register default shortcut '{}' ({}): {}", id, accelerator, e);
+        }
+    }
+
+    let _ = crate::database::set_setting(
+        app.state::<Database>(),
+        DEFAULTS_REGISTERED_SETTING.to_string(),
+        "true".to_string(),
+        "bool".to_string(),
+        "shortcuts".to_string(),
+    );
+}
+
+/// Toggles a shortcut's OS-level registration without forgetting its accelerator, so
+/// disabling it is reversible (unlike unregister_shortcut, which drops the config).
+#[tauri::command]
+pub fn set_shortcut_enabled(app: AppHandle, shortcut_id: String, enabled: bool) -> Result<(), String> {
+    let state = app.state::<ShortcutState>();
+    let mut config = state
+        .shortcuts
+        .lock()
+        .unwrap()
+        .get(&shortcut_id)
+        .cloned()
+        .ok_or_else(|| format!("no shortcut registered with id {}", shortcut_id))?;
+
+    if config.enabled != enabled {
+        let mut manager = app.global_shortcut_manager();
+        if enabled {
+            manager
+                .register(&config.accelerator, emit_on_trigger(&app, &shortcut_id))
+                .map_err(|e| e.to_string())?;
+        } else {
+            manager.unregister(&config.accelerator).map_err(|e| e.to_string())?;
+        }
+        config.enabled = enabled;
+    }
+
+    state.shortcuts.lock().unwrap().insert(shortcut_id, config);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::api::notification::Notification;
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, State, SystemTray, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem,
+};
+
+use crate::database::Database;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentCapture {
+    pub id: i64,
+    pub timestamp: String,
+    pub text: String,
+}
+
+pub struct TrayState {
+    pub recent_captures: Mutex<Vec<RecentCapture>>,
+}
+
+impl Default for TrayState {
+    fn default() -> Self {
+        TrayState {
+            recent_captures: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+pub(crate) const MAX_RECENT_IN_MENU: usize = 5;
+
+pub fn build_system_tray() -> SystemTray {
+    SystemTray::new().with_menu(build_menu(&[]))
+}
+
+fn build_menu(recent: &[RecentCapture]) -> SystemTrayMenu {
+    let mut menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("capture_fullscreen".to_string(), "Capture Fullscreen"))
+        .add_item(CustomMenuItem::new("capture_region".to_string(), "Capture Region"))
+        .add_native_item(SystemTrayMenuItem::Separator);
+
+    if recent.is_empty() {
+        menu = menu.add_item(CustomMenuItem::new("no_recent".to_string(), "No Recent Captures").disabled());
+    } else {
+        for capture in recent.iter().take(MAX_RECENT_IN_MENU) {
+            let label = format!("{}  {}", capture.timestamp, truncate(&capture.text, 40));
+            menu = menu.add_item(CustomMenuItem::new(format!("recent_{}", capture.id), label));
+        }
+    }
+
+    menu.add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit".to_string(), "Quit"))
+}
+
+fn truncate(text: &str, max_len: usize) -> String {
+    if text.len() <= max_len {
+        text.to_string()
+    } else {
+        format!("{}…", &text[..max_len])
+    }
+}
+
+/// Tauri v1's system tray has no API to patch a single submenu, so the whole tray
+/// menu is rebuilt and swapped in via `set_menu` whenever the recent-captures list changes.
+#[tauri::command]
+pub fn tray_update_recent_captures(app: AppHandle, captures: Vec<RecentCapture>) -> Result<(), String> {
+    let state = app.state::<TrayState>();
+    {
+        let mut recent = state.recent_captures.lock().map_err(|e| e.to_string())?;
+        *recent = captures;
+    }
+    let recent = state.recent_captures.lock().map_err(|e| e.to_string())?;
+    app.tray_handle().set_menu(build_menu(&recent)).map_err(|e| e.to_string())
+}
+
+/// Pulls the most recent OCR records straight from the database and maps them to
+/// `RecentCapture`, so the tray's recent list survives an app restart instead of only
+/// existing once the frontend has called `tray_update_recent_captures` again.
+#[tauri::command]
+pub fn get_recent_captures(state: State<Database>, limit: i64) -> Result<Vec<RecentCapture>, String> {
+    let records = crate::database::get_all_ocr_records(state, limit, 0)?;
+    Ok(records
+        .into_iter()
+        .map(|record| RecentCapture {
+            id: record.id.unwrap_or_default(),
+            timestamp: format_timestamp(record.timestamp),
+            text: truncate(record.text.trim(), NOTIFICATION_PREVIEW_LEN),
+        })
+        .collect())
+}
+
+fn format_timestamp(timestamp_ms: i64) -> String {
+    Local
+        .timestamp_millis_opt(timestamp_ms)
+        .single()
+        .map(|dt| dt.format("%H:%M").to_string())
+        .unwrap_or_default()
+}
+
+const NOTIFICATION_PREVIEW_LEN: usize = 40;
+
+/// Shown when a capture finishes, so background/tray-triggered OCR runs don't go unnoticed
+/// while the main window is hidden. Callers should check the `tray_notifications` setting first.
+pub fn notify_capture_done(app: &AppHandle, text: &str) {
+    let preview = truncate(text.trim(), NOTIFICATION_PREVIEW_LEN);
+    let identifier = &app.config().tauri.bundle.identifier;
+    let _ = Notification::new(identifier)
+        .title("OCR complete")
+        .body(format!("OCR complete — {}", preview))
+        .show();
+}
+
+pub fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
+    if let SystemTrayEvent::MenuItemClick { id, .. } = event {
+        match id.as_str() {
+            "quit" => {
+                app.exit(0);
+            }
+            "capture_fullscreen" | "capture_region" => {
+                let _ = app.emit_all(&format!("tray-{}", id.replace('_', "-")), ());
+            }
+            other if other.starts_with("recent_") => {
+                if let Ok(record_id) = other.trim_start_matches("recent_").parse::<i64>() {
+                    let _ = app.emit_all("open-ocr-record", record_id);
+                }
+            }
+            _ => {}
+        }
+    }
+}